@@ -0,0 +1,138 @@
+//! Recorded answers for the real puzzle inputs, used to catch regressions.
+//!
+//! The answers live in `answers.json` inside the input directory, since they
+//! are only correct for the inputs stored alongside them:
+//!
+//! ```json
+//! {
+//!   "1": { "part1": "2086478", "part2": "24941624" }
+//! }
+//! ```
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::input;
+use crate::solution::{Answer, Part};
+
+/// Name of the answers file inside the input directory.
+pub const FILE_NAME: &str = "answers.json";
+
+/// Path of the answers file in the configured input directory.
+pub fn path() -> PathBuf {
+    input::dir().join(FILE_NAME)
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DayAnswers {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part1: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part2: Option<String>,
+}
+
+impl DayAnswers {
+    fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+
+    fn slot(&mut self, part: Part) -> &mut Option<String> {
+        match part {
+            Part::One => &mut self.part1,
+            Part::Two => &mut self.part2,
+        }
+    }
+}
+
+/// Every recorded answer, keyed by day.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Answers {
+    days: BTreeMap<u8, DayAnswers>,
+}
+
+/// How a computed answer compares to the recorded one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Check {
+    Match,
+    Mismatch { expected: String },
+    Unrecorded,
+}
+
+impl Answers {
+    /// Loads the answers at `path`, treating a missing file as having no
+    /// answers recorded yet.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(e),
+        };
+        serde_json::from_str(&contents).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let mut contents = serde_json::to_string_pretty(self)?;
+        contents.push('\n');
+        fs::write(path, contents)
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        self.days.get(&day)?.get(part)
+    }
+
+    pub fn record(&mut self, day: u8, answer: &Answer) {
+        *self.days.entry(day).or_default().slot(answer.part) = Some(answer.value.clone());
+    }
+
+    pub fn check(&self, day: u8, answer: &Answer) -> Check {
+        match self.get(day, answer.part) {
+            None => Check::Unrecorded,
+            Some(expected) if expected == answer.value => Check::Match,
+            Some(expected) => Check::Mismatch {
+                expected: expected.to_owned(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_compares_against_recorded() {
+        let mut answers = Answers::default();
+        answers.record(6, &Answer::new(Part::One, 41));
+
+        assert_eq!(answers.check(6, &Answer::new(Part::One, 41)), Check::Match);
+        assert_eq!(
+            answers.check(6, &Answer::new(Part::One, 40)),
+            Check::Mismatch {
+                expected: "41".to_owned()
+            }
+        );
+        assert_eq!(
+            answers.check(6, &Answer::new(Part::Two, 6)),
+            Check::Unrecorded
+        );
+    }
+
+    #[test]
+    fn round_trips_through_json() {
+        let json = r#"{"1": {"part1": "11", "part2": "31"}, "2": {"part1": "2"}}"#;
+        let answers: Answers = serde_json::from_str(json).unwrap();
+        assert_eq!(answers.get(1, Part::Two), Some("31"));
+        assert_eq!(answers.get(2, Part::Two), None);
+
+        let again = serde_json::to_string(&answers).unwrap();
+        assert_eq!(serde_json::from_str::<Answers>(&again).unwrap(), answers);
+    }
+}
@@ -0,0 +1,233 @@
+//! Repeated timing of the solvers, summarised per phase.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::solution::{SolveError, Solver};
+
+/// Summary of the durations of one phase over every iteration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub max_ns: u64,
+}
+
+impl Stats {
+    /// Summarises `samples`, which must not be empty.
+    pub fn from_samples(samples: &mut [Duration]) -> Self {
+        assert!(!samples.is_empty(), "no samples to summarise");
+        samples.sort();
+        let nanos = |d: Duration| d.as_nanos() as u64;
+        Self {
+            min_ns: nanos(samples[0]),
+            median_ns: nanos(samples[samples.len() / 2]),
+            max_ns: nanos(samples[samples.len() - 1]),
+        }
+    }
+
+    pub fn min(&self) -> Duration {
+        Duration::from_nanos(self.min_ns)
+    }
+
+    pub fn median(&self) -> Duration {
+        Duration::from_nanos(self.median_ns)
+    }
+
+    pub fn max(&self) -> Duration {
+        Duration::from_nanos(self.max_ns)
+    }
+}
+
+/// Timings of a single day.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DayBench {
+    pub day: u8,
+    pub iterations: usize,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+impl DayBench {
+    /// Each phase with its name, in the order they run.
+    pub fn phases(&self) -> [(&'static str, &Stats); 3] {
+        [
+            ("parse", &self.parse),
+            ("part1", &self.part1),
+            ("part2", &self.part2),
+        ]
+    }
+}
+
+/// The results of benchmarking a set of days.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Report {
+    pub days: Vec<DayBench>,
+}
+
+impl Report {
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let contents = fs::read_to_string(path)?;
+        serde_json::from_str(&contents).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let mut contents = serde_json::to_string_pretty(self)?;
+        contents.push('\n');
+        fs::write(path, contents)
+    }
+
+    /// Replaces the timings of every day in `newer`, keeping the days it did
+    /// not benchmark.
+    pub fn update(&mut self, newer: &Report) {
+        for day in &newer.days {
+            match self.days.iter_mut().find(|d| d.day == day.day) {
+                Some(old) => *old = day.clone(),
+                None => self.days.push(day.clone()),
+            }
+        }
+        self.days.sort_by_key(|d| d.day);
+    }
+
+    /// Every phase whose median is more than `threshold` percent slower than
+    /// in `baseline`. Days missing from the baseline are skipped.
+    pub fn regressions(&self, baseline: &Report, threshold: f64) -> Vec<Regression> {
+        let mut regressions = Vec::new();
+        for current in &self.days {
+            let Some(before) = baseline.days.iter().find(|b| b.day == current.day) else {
+                continue;
+            };
+            for ((phase, now), (_, then)) in current.phases().into_iter().zip(before.phases()) {
+                let slowdown = percent_change(then.median_ns, now.median_ns);
+                if slowdown > threshold {
+                    regressions.push(Regression {
+                        day: current.day,
+                        phase,
+                        baseline: then.median(),
+                        current: now.median(),
+                        slowdown,
+                    });
+                }
+            }
+        }
+        regressions
+    }
+}
+
+/// A phase that got slower than the allowed threshold.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Regression {
+    pub day: u8,
+    pub phase: &'static str,
+    pub baseline: Duration,
+    pub current: Duration,
+    /// How much slower the median got, in percent.
+    pub slowdown: f64,
+}
+
+/// Default location of the saved baseline, next to `Cargo.toml`.
+///
+/// Timings only mean something on the machine that produced them, so the file
+/// is not checked in.
+pub fn baseline_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("bench_baseline.json")
+}
+
+fn percent_change(before: u64, after: u64) -> f64 {
+    if before == 0 {
+        return 0.0;
+    }
+    (after as f64 - before as f64) / before as f64 * 100.0
+}
+
+/// Runs `solver` on `input` `iterations` times and summarises each phase.
+pub fn bench(solver: &Solver, input: &str, iterations: usize) -> Result<DayBench, SolveError> {
+    let iterations = iterations.max(1);
+    let mut parse = Vec::with_capacity(iterations);
+    let mut part1 = Vec::with_capacity(iterations);
+    let mut part2 = Vec::with_capacity(iterations);
+    for _ in 0..iterations {
+        let timing = solver.time(input)?;
+        parse.push(timing.parse);
+        part1.push(timing.part1);
+        part2.push(timing.part2);
+    }
+    Ok(DayBench {
+        day: solver.day,
+        iterations,
+        parse: Stats::from_samples(&mut parse),
+        part1: Stats::from_samples(&mut part1),
+        part2: Stats::from_samples(&mut part2),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_sort_their_samples() {
+        let mut samples = [5, 1, 4, 2, 3].map(Duration::from_nanos);
+        let stats = Stats::from_samples(&mut samples);
+        assert_eq!((stats.min_ns, stats.median_ns, stats.max_ns), (1, 3, 5));
+    }
+
+    fn day(day: u8, median_ns: u64) -> DayBench {
+        let stats = Stats {
+            min_ns: median_ns,
+            median_ns,
+            max_ns: median_ns,
+        };
+        DayBench {
+            day,
+            iterations: 1,
+            parse: stats,
+            part1: stats,
+            part2: Stats {
+                median_ns: 2 * median_ns,
+                ..stats
+            },
+        }
+    }
+
+    #[test]
+    fn only_slowdowns_past_the_threshold_regress() {
+        let baseline = Report {
+            days: vec![day(1, 100), day(2, 100)],
+        };
+        let current = Report {
+            days: vec![day(1, 105), day(2, 150), day(3, 1000)],
+        };
+
+        let regressions = current.regressions(&baseline, 10.0);
+        let found: Vec<_> = regressions.iter().map(|r| (r.day, r.phase)).collect();
+        assert_eq!(found, vec![(2, "parse"), (2, "part1"), (2, "part2")]);
+        assert_eq!(regressions[0].slowdown, 50.0);
+
+        assert!(current.regressions(&baseline, 60.0).is_empty());
+    }
+
+    #[test]
+    fn updates_keep_days_not_benchmarked() {
+        let mut baseline = Report {
+            days: vec![day(1, 100), day(6, 100)],
+        };
+        baseline.update(&Report {
+            days: vec![day(6, 90), day(2, 50)],
+        });
+        assert_eq!(baseline.days, [day(1, 100), day(2, 50), day(6, 90)]);
+    }
+
+    #[test]
+    fn bench_runs_every_iteration() {
+        let solver = crate::solution::solver(1).unwrap();
+        let day = bench(&solver, "3   4\n4   3\n", 3).unwrap();
+        assert_eq!((day.day, day.iterations), (1, 3));
+        assert!(day.parse.min_ns <= day.parse.max_ns);
+    }
+}
@@ -0,0 +1,124 @@
+//! `aoc bench`: time each phase of the solvers over many runs.
+
+use std::path::PathBuf;
+use std::time::Duration;
+
+use aoc::bench::{self, DayBench, Report};
+use aoc::input::Source;
+
+use crate::{days_or_all, find_solver, parse_day, read_input, CliError};
+
+pub fn bench(args: &[String]) -> Result<(), CliError> {
+    let mut days = Vec::new();
+    let mut iterations = 10;
+    let mut json = false;
+    let mut save = false;
+    let mut compare = false;
+    let mut threshold = 10.0;
+    let mut baseline = bench::baseline_path();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--iterations" | "-n" => {
+                let n = args.next().ok_or("--iterations needs a value")?;
+                iterations = n
+                    .parse()
+                    .map_err(|_| format!("iterations must be a number, found {n:?}"))?;
+            }
+            "--json" => json = true,
+            "--save" => save = true,
+            "--compare" => compare = true,
+            "--baseline" => {
+                let path = args.next().ok_or("--baseline needs a file")?;
+                baseline = PathBuf::from(path);
+            }
+            "--threshold" => {
+                let pct = args.next().ok_or("--threshold needs a percentage")?;
+                threshold = pct
+                    .trim_end_matches('%')
+                    .parse()
+                    .map_err(|_| format!("threshold must be a percentage, found {pct:?}"))?;
+            }
+            _ => days.push(parse_day(arg)?),
+        }
+    }
+
+    let mut report = Report::default();
+    for day in days_or_all(days) {
+        let solver = find_solver(day)?;
+        let source = Source::Day(day);
+        let contents = read_input(&source)?;
+        let result = bench::bench(&solver, &contents, iterations)
+            .map_err(|e| CliError::Failed(e.in_file(source.to_string()).to_string()))?;
+        if !json {
+            print_day(&result);
+        }
+        report.days.push(result);
+    }
+
+    if json {
+        let json = serde_json::to_string_pretty(&report)
+            .map_err(|e| CliError::Failed(format!("unable to serialize report: {e}")))?;
+        println!("{json}");
+    }
+
+    let load = || {
+        Report::load(&baseline)
+            .map_err(|e| CliError::Failed(format!("unable to load {}: {e}", baseline.display())))
+    };
+    let mut before = None;
+    let mut regressions = Vec::new();
+    if compare {
+        let loaded = before.insert(load()?);
+        regressions = report.regressions(loaded, threshold);
+        for r in &regressions {
+            eprintln!(
+                "REGRESSION day {:>2} {}: median {} -> {} (+{:.1}%)",
+                r.day,
+                r.phase,
+                format_duration(r.baseline),
+                format_duration(r.current),
+                r.slowdown,
+            );
+        }
+    }
+    if save && !regressions.is_empty() {
+        eprintln!("not saving a baseline that regressed");
+    } else if save {
+        // only the days benchmarked this time are replaced
+        let mut saved = match before {
+            Some(before) => before,
+            None if baseline.exists() => load()?,
+            None => Report::default(),
+        };
+        saved.update(&report);
+        saved
+            .save(&baseline)
+            .map_err(|e| CliError::Failed(format!("unable to save {}: {e}", baseline.display())))?;
+        eprintln!("saved baseline to {}", baseline.display());
+    }
+    if !regressions.is_empty() {
+        return Err(CliError::Failed(format!(
+            "{} phase(s) slower than the baseline by more than {threshold}%",
+            regressions.len()
+        )));
+    }
+    Ok(())
+}
+
+fn print_day(result: &DayBench) {
+    for (name, stats) in result.phases() {
+        println!(
+            "day {:>2} {name}: min {:>10} median {:>10} max {:>10}",
+            result.day,
+            format_duration(stats.min()),
+            format_duration(stats.median()),
+            format_duration(stats.max()),
+        );
+    }
+}
+
+pub fn format_duration(d: Duration) -> String {
+    format!("{d:.2?}")
+}
@@ -0,0 +1,471 @@
+//! `aoc explore`: step through a grid day interactively in the terminal.
+//!
+//! The day builds an [`Exploration`] up front, so moving backwards is as cheap
+//! as moving forwards; this module only tracks what is on screen and draws it.
+
+use std::io;
+use std::time::Duration;
+
+use aoc::explore::{self, Cell, Exploration};
+use aoc::input::Source;
+use aoc::render::{Palette, Rgb};
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Paragraph};
+use ratatui::{DefaultTerminal, Frame};
+
+use crate::{parse_day, read_input, CliError};
+
+const HELP: &str = "n/p step  [/] jump  home/end  space play  </> speed  wasd pan  +/- zoom  \
+                    f follow  1-9 layers  q quit";
+
+pub fn explore(args: &[String]) -> Result<(), CliError> {
+    let mut day = None;
+    let mut path = None;
+    let mut palette = Palette::default();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--palette" => {
+                palette = Palette::parse(args.next().ok_or("--palette needs colours")?)?;
+            }
+            _ if day.is_none() => day = Some(parse_day(arg)?),
+            _ if path.is_none() => path = Some(arg.as_str()),
+            _ => return Err(format!("unexpected argument {arg:?}").into()),
+        }
+    }
+
+    let day = day.ok_or("missing day")?;
+    let source = Source::from_arg(day, path);
+    let contents = read_input(&source)?;
+    let exploration = explore::explore(day, &contents, &palette)
+        .ok_or_else(|| CliError::Failed(format!("day {day} cannot be explored")))?
+        .map_err(|e| CliError::Failed(e.in_file(source.to_string()).to_string()))?;
+
+    let failed = |e: io::Error| CliError::Failed(format!("terminal error: {e}"));
+    let mut terminal = ratatui::try_init().map_err(failed)?;
+    let result = View::new(&exploration).run(&mut terminal);
+    ratatui::restore();
+    result.map_err(failed)
+}
+
+/// How grid cells map to terminal characters at a zoom level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Scale {
+    /// Grid cells per character along each axis, when zoomed out.
+    stride: usize,
+    /// Characters per cell horizontally and vertically, when zoomed in.
+    width: usize,
+    height: usize,
+}
+
+impl Scale {
+    const MIN_ZOOM: i32 = -3;
+    const MAX_ZOOM: i32 = 2;
+
+    fn at(zoom: i32) -> Self {
+        match zoom {
+            ..=-1 => Scale {
+                stride: 1 << -zoom,
+                width: 1,
+                height: 1,
+            },
+            0 => Scale {
+                stride: 1,
+                width: 1,
+                height: 1,
+            },
+            // terminal characters are about twice as high as wide
+            z => Scale {
+                stride: 1,
+                width: 2 << (z - 1),
+                height: 1 << (z - 1),
+            },
+        }
+    }
+
+    /// How many grid rows and columns fit in `area` characters.
+    fn visible(self, (rows, cols): (usize, usize)) -> (usize, usize) {
+        (
+            (rows / self.height).max(1) * self.stride,
+            (cols / self.width).max(1) * self.stride,
+        )
+    }
+}
+
+/// What the explorer currently shows.
+struct View<'a> {
+    exploration: &'a Exploration,
+    step: usize,
+    shown: Vec<bool>,
+    zoom: i32,
+    /// The grid cell in the top left corner of the map.
+    origin: (usize, usize),
+    /// The size of the map area in characters, from the last draw.
+    area: (usize, usize),
+    follow: bool,
+    playing: bool,
+    /// Steps advanced per tick while playing.
+    speed: usize,
+}
+
+impl<'a> View<'a> {
+    fn new(exploration: &'a Exploration) -> Self {
+        Self {
+            exploration,
+            step: 0,
+            shown: vec![true; exploration.layers().len()],
+            zoom: 0,
+            origin: (0, 0),
+            area: (24, 80),
+            follow: true,
+            playing: false,
+            speed: 1,
+        }
+    }
+
+    fn run(mut self, terminal: &mut DefaultTerminal) -> io::Result<()> {
+        loop {
+            terminal.draw(|frame| self.draw(frame))?;
+            let tick = if self.playing { 40 } else { 500 };
+            if !event::poll(Duration::from_millis(tick))? {
+                if self.playing {
+                    self.go_to(self.step + self.speed);
+                    self.playing = self.step + 1 < self.exploration.steps().len();
+                }
+                continue;
+            }
+            let Event::Key(key) = event::read()? else {
+                continue;
+            };
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+            match key.code {
+                KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                code => self.handle(code),
+            }
+        }
+    }
+
+    fn handle(&mut self, code: KeyCode) {
+        let jump = (self.exploration.steps().len() / 20).max(1);
+        let (rows, cols) = self.scale().visible(self.area);
+        match code {
+            KeyCode::Char('n') | KeyCode::Right => self.go_to(self.step + 1),
+            KeyCode::Char('p') | KeyCode::Left => self.go_to(self.step.saturating_sub(1)),
+            KeyCode::Char(']') => self.go_to(self.step + jump),
+            KeyCode::Char('[') => self.go_to(self.step.saturating_sub(jump)),
+            KeyCode::Home => self.go_to(0),
+            KeyCode::End => self.go_to(usize::MAX),
+            KeyCode::Char(' ') => self.playing = !self.playing,
+            KeyCode::Char('>') => self.speed = (self.speed * 2).min(1 << 12),
+            KeyCode::Char('<') => self.speed = (self.speed / 2).max(1),
+            KeyCode::Char('w') | KeyCode::Up => self.pan(-(rows as i32 / 4).max(1), 0),
+            KeyCode::Char('s') | KeyCode::Down => self.pan((rows as i32 / 4).max(1), 0),
+            KeyCode::Char('a') => self.pan(0, -(cols as i32 / 4).max(1)),
+            KeyCode::Char('d') => self.pan(0, (cols as i32 / 4).max(1)),
+            KeyCode::Char('+') | KeyCode::Char('=') => self.zoom_by(1),
+            KeyCode::Char('-') => self.zoom_by(-1),
+            KeyCode::Char('f') => {
+                self.follow = !self.follow;
+                self.centre_on_cursor();
+            }
+            KeyCode::Char(c @ '1'..='9') => {
+                let layer = c as usize - '1' as usize;
+                if let Some(shown) = self.shown.get_mut(layer) {
+                    *shown = !*shown;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn scale(&self) -> Scale {
+        Scale::at(self.zoom)
+    }
+
+    fn go_to(&mut self, step: usize) {
+        self.step = step.min(self.exploration.steps().len().saturating_sub(1));
+        self.centre_on_cursor();
+    }
+
+    fn zoom_by(&mut self, by: i32) {
+        self.zoom = (self.zoom + by).clamp(Scale::MIN_ZOOM, Scale::MAX_ZOOM);
+        self.clamp_origin();
+        self.centre_on_cursor();
+    }
+
+    /// Moves the view by `rows` and `cols` grid cells, stopping at the edges.
+    fn pan(&mut self, rows: i32, cols: i32) {
+        self.follow = false;
+        let shift = |x: usize, by: i32| (x as i64 + i64::from(by)).max(0) as usize;
+        self.origin = (shift(self.origin.0, rows), shift(self.origin.1, cols));
+        self.clamp_origin();
+    }
+
+    fn clamp_origin(&mut self) {
+        let (n, m) = self.exploration.size();
+        let (rows, cols) = self.scale().visible(self.area);
+        self.origin = (
+            self.origin.0.min(n.saturating_sub(rows)),
+            self.origin.1.min(m.saturating_sub(cols)),
+        );
+    }
+
+    fn centre_on_cursor(&mut self) {
+        if !self.follow {
+            return;
+        }
+        let cursor = self
+            .exploration
+            .steps()
+            .get(self.step)
+            .and_then(|s| s.cursor);
+        let Some((at, _)) = cursor else {
+            return;
+        };
+        let Some((i, j)) = at.to_index() else {
+            return;
+        };
+        let (rows, cols) = self.scale().visible(self.area);
+        // only scroll once the cursor gets near an edge, so the map does not
+        // jitter with every step
+        let margin = |visible: usize| visible / 5;
+        let (top, left) = self.origin;
+        if i < top + margin(rows) || i + margin(rows) >= top + rows {
+            self.origin.0 = i.saturating_sub(rows / 2);
+        }
+        if j < left + margin(cols) || j + margin(cols) >= left + cols {
+            self.origin.1 = j.saturating_sub(cols / 2);
+        }
+        self.clamp_origin();
+    }
+
+    /// The cell drawn for the block of `stride` by `stride` grid cells at
+    /// `(i, j)`: the cursor if it is in the block, else the first cell some
+    /// shown layer marks, else the top left cell.
+    fn block(&self, (i, j): (usize, usize), stride: usize) -> Option<Cell> {
+        let (n, m) = self.exploration.size();
+        if i >= n || j >= m {
+            return None;
+        }
+        let at = |pos| self.exploration.cell(pos, self.step, &self.shown);
+        if stride == 1 {
+            return Some(at((i, j)));
+        }
+        let hidden = vec![false; self.shown.len()];
+        let cursor = self.exploration.steps()[self.step].cursor;
+        let positions =
+            (i..(i + stride).min(n)).flat_map(|i| (j..(j + stride).min(m)).map(move |j| (i, j)));
+        let mut marked = None;
+        for pos in positions {
+            let cell = at(pos);
+            if cursor.is_some_and(|(p, _)| p.to_index() == Some(pos)) {
+                return Some(cell);
+            }
+            if marked.is_none() && cell != self.exploration.cell(pos, self.step, &hidden) {
+                marked = Some(cell);
+            }
+        }
+        marked.or(Some(at((i, j))))
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let [map, status] =
+            Layout::vertical([Constraint::Min(3), Constraint::Length(4)]).areas(frame.area());
+        let inner = Block::bordered().inner(map);
+        self.area = (inner.height as usize, inner.width as usize);
+        self.clamp_origin();
+
+        let steps = self.exploration.steps();
+        let title = format!(
+            " {} - step {}/{} ",
+            self.exploration.title,
+            self.step + 1,
+            steps.len()
+        );
+        frame.render_widget(
+            Paragraph::new(self.map_lines(inner)).block(Block::bordered().title(title)),
+            map,
+        );
+
+        let layers: Vec<Span> = self
+            .exploration
+            .layers()
+            .iter()
+            .zip(&self.shown)
+            .enumerate()
+            .map(|(k, (layer, &shown))| {
+                let mark = if shown { 'x' } else { ' ' };
+                Span::raw(format!("[{mark}] {} {}  ", k + 1, layer.name))
+            })
+            .collect();
+        let state = format!(
+            "zoom {}  {}  {}",
+            self.zoom,
+            if self.follow { "following" } else { "free" },
+            if self.playing {
+                format!("playing x{}", self.speed)
+            } else {
+                format!("paused x{}", self.speed)
+            }
+        );
+        let lines = vec![
+            Line::raw(steps[self.step].note.clone()),
+            Line::from(layers).patch_style(Style::new().add_modifier(Modifier::DIM)),
+            Line::raw(state),
+            Line::raw(HELP).style(Style::new().add_modifier(Modifier::DIM)),
+        ];
+        frame.render_widget(Paragraph::new(lines), status);
+    }
+
+    fn map_lines(&self, area: Rect) -> Vec<Line<'static>> {
+        let scale = self.scale();
+        let (top, left) = self.origin;
+        let rows = area.height as usize / scale.height;
+        let cols = area.width as usize / scale.width;
+        let mut lines = Vec::with_capacity(area.height as usize);
+        for r in 0..rows {
+            let cells: Vec<Option<Cell>> = (0..cols)
+                .map(|c| {
+                    let pos = (top + r * scale.stride, left + c * scale.stride);
+                    self.block(pos, scale.stride)
+                })
+                .collect();
+            for sub in 0..scale.height {
+                let spans = cells.iter().map_while(|cell| {
+                    let cell = (*cell)?;
+                    let glyph = if sub == 0 { cell.glyph } else { ' ' };
+                    let text = format!("{glyph:<width$}", width = scale.width);
+                    Some(Span::styled(text, style(cell)))
+                });
+                lines.push(Line::from(spans.collect::<Vec<_>>()));
+            }
+        }
+        lines
+    }
+}
+
+fn style(cell: Cell) -> Style {
+    let colour = |Rgb(r, g, b): Rgb| Color::Rgb(r, g, b);
+    let style = Style::new().fg(colour(cell.fg)).bg(colour(cell.bg));
+    if cell.bold {
+        style.add_modifier(Modifier::BOLD)
+    } else {
+        style
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../../inputs/examples/day06.txt");
+
+    fn exploration() -> Exploration {
+        explore::explore(6, EXAMPLE, &Palette::default())
+            .unwrap()
+            .unwrap()
+    }
+
+    #[test]
+    fn scales_fit_the_area() {
+        assert_eq!(Scale::at(0).visible((10, 20)), (10, 20));
+        assert_eq!(Scale::at(-2).visible((10, 20)), (40, 80));
+        assert_eq!(Scale::at(1).visible((10, 20)), (10, 10));
+        assert_eq!(Scale::at(2).visible((10, 20)), (5, 5));
+    }
+
+    #[test]
+    fn steps_stop_at_both_ends() {
+        let exploration = exploration();
+        let mut view = View::new(&exploration);
+        view.handle(KeyCode::Char('p'));
+        assert_eq!(view.step, 0);
+        view.handle(KeyCode::End);
+        assert_eq!(view.step, exploration.steps().len() - 1);
+        view.handle(KeyCode::Char('n'));
+        assert_eq!(view.step, exploration.steps().len() - 1);
+    }
+
+    #[test]
+    fn panning_stays_on_the_grid() {
+        let exploration = exploration();
+        let mut view = View::new(&exploration);
+        view.area = (4, 4);
+        for _ in 0..10 {
+            view.handle(KeyCode::Char('d'));
+            view.handle(KeyCode::Char('s'));
+        }
+        assert_eq!(view.origin, (6, 6));
+        assert!(!view.follow);
+        for _ in 0..10 {
+            view.handle(KeyCode::Char('w'));
+        }
+        assert_eq!(view.origin, (0, 6));
+    }
+
+    #[test]
+    fn following_keeps_the_cursor_in_view() {
+        let exploration = exploration();
+        let mut view = View::new(&exploration);
+        view.area = (4, 4);
+        for _ in 0..30 {
+            view.handle(KeyCode::Char('n'));
+            let (at, _) = exploration.steps()[view.step].cursor.unwrap();
+            let (i, j) = at.to_index().unwrap();
+            let (top, left) = view.origin;
+            assert!((top..top + 4).contains(&i) && (left..left + 4).contains(&j));
+        }
+    }
+
+    #[test]
+    fn zoomed_out_blocks_show_the_cursor() {
+        let exploration = exploration();
+        let mut view = View::new(&exploration);
+        view.handle(KeyCode::Char('-'));
+        view.handle(KeyCode::Char('-'));
+        // the guard starts at row 6, column 4
+        let cell = view.block((4, 4), 4).unwrap();
+        assert_eq!(cell.glyph, '^');
+        assert!(view.block((12, 0), 4).is_none());
+    }
+
+    #[test]
+    fn draws_the_map_and_status() {
+        let exploration = exploration();
+        let mut view = View::new(&exploration);
+        view.handle(KeyCode::Char('+'));
+        let mut terminal =
+            ratatui::Terminal::new(ratatui::backend::TestBackend::new(40, 16)).unwrap();
+        terminal.draw(|frame| view.draw(frame)).unwrap();
+        let screen: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(screen.contains("step 1/"), "{screen}");
+        assert!(screen.contains("^ "), "{screen}");
+        assert!(
+            screen.contains("the guard starts at row 7, column 5"),
+            "{screen}"
+        );
+        assert_eq!(view.area, (10, 38));
+    }
+
+    #[test]
+    fn layers_toggle_by_number() {
+        let exploration = exploration();
+        let mut view = View::new(&exploration);
+        view.handle(KeyCode::Char('2'));
+        assert_eq!(view.shown, [true, false]);
+        view.handle(KeyCode::Char('9'));
+        assert_eq!(view.shown, [true, false]);
+    }
+}
@@ -0,0 +1,35 @@
+//! `aoc fetch`: download inputs that are not in the input directory yet.
+
+use aoc::fetch::{self, Client};
+use aoc::input;
+
+use crate::{days_or_all, parse_day, CliError};
+
+pub fn fetch(args: &[String]) -> Result<(), CliError> {
+    let days = args
+        .iter()
+        .map(|arg| parse_day(arg))
+        .collect::<Result<Vec<u8>, _>>()?;
+
+    let dir = input::dir();
+    let mut client = None;
+    for day in days_or_all(days) {
+        let path = input::path_in(&dir, day);
+        if fetch::is_cached(&path) {
+            println!("day {day:>2}: cached {}", path.display());
+            continue;
+        }
+        // only insist on a session once something actually has to be fetched
+        let client = match &client {
+            Some(client) => client,
+            None => client.insert(Client::from_env().map_err(failed)?),
+        };
+        let path = fetch::cached(&dir, day, client).map_err(failed)?;
+        println!("day {day:>2}: fetched {}", path.display());
+    }
+    Ok(())
+}
+
+fn failed(e: fetch::FetchError) -> CliError {
+    CliError::Failed(e.to_string())
+}
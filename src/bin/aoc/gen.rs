@@ -0,0 +1,42 @@
+//! `aoc gen`: print a synthetic input for a day.
+
+use aoc::gen::{self, Params};
+
+use crate::{parse_day, CliError};
+
+pub fn gen(args: &[String]) -> Result<(), CliError> {
+    let mut day = None;
+    let mut seed = 0;
+    let mut size = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--seed" => {
+                let n = args.next().ok_or("--seed needs a value")?;
+                seed = n
+                    .parse()
+                    .map_err(|_| format!("seed must be a number, found {n:?}"))?;
+            }
+            "--size" => {
+                let n = args.next().ok_or("--size needs a value")?;
+                size = Some(
+                    n.parse()
+                        .map_err(|_| format!("size must be a number, found {n:?}"))?,
+                );
+            }
+            _ if day.is_none() => day = Some(parse_day(arg)?),
+            _ => return Err(format!("unexpected argument {arg:?}").into()),
+        }
+    }
+
+    let day = day.ok_or("missing day")?;
+    let no_generator = || CliError::Failed(format!("day {day} has no input generator"));
+    let size = match size {
+        Some(size) => size,
+        None => gen::default_size(day).ok_or_else(no_generator)?,
+    };
+    let input = gen::generate(day, Params { seed, size }).ok_or_else(no_generator)?;
+    print!("{input}");
+    Ok(())
+}
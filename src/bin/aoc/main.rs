@@ -0,0 +1,184 @@
+use std::process::ExitCode;
+
+use aoc::input::Source;
+use aoc::solution::{self, Answer, Part, Run, Solver};
+
+mod bench;
+mod explore;
+mod fetch;
+mod gen;
+mod new;
+mod record;
+mod render;
+mod run;
+mod summary;
+mod verify;
+mod watch;
+
+const USAGE: &str = "\
+usage: aoc <command>
+
+commands:
+    run <day|all> [input] [--part 1|2] [--format text|json] [--parallel]
+        [--explain]
+        solve a day, both parts unless --part is given. The input defaults to
+        dayXX.txt in the inputs directory; pass a file path or - for stdin.
+        `all` solves every day from its default input and prints a summary
+        table with timings, failures and the slowest parts; --parallel
+        solves the days concurrently. --format json prints one JSON object
+        per part with the answer, timing and input path. --explain prints,
+        before each answer, every item of the input with its verdict and
+        what it added to the answer (days 2, 3, 5 and 7).
+    verify [day...] [--record]
+        solve every day (or the given days) and compare the answers with the
+        recorded ones in answers.json, failing on any mismatch. --record
+        saves answers for parts that have none recorded yet.
+    bench [day...] [--iterations N] [--json] [--save] [--compare]
+          [--baseline FILE] [--threshold PCT]
+        time parsing and both parts of every day (or the given days) over N
+        runs (default 10), reporting the min, median and max of each phase.
+        --save writes the results to the baseline file; --compare fails if a
+        median got slower than the baseline by more than --threshold percent
+        (default 10). --baseline FILE overrides bench_baseline.json.
+    new <day>
+        scaffold src/days/dayXX.rs from the src/dayXX.rs template, register
+        it with the runner and create empty input and example files.
+    gen <day> [--seed N] [--size N]
+        print a synthetic input for a day, the same for the same seed
+        (default 0). --size scales it: lines for list days, the side for
+        grid days; it defaults to the size of the real input.
+    fetch [day...]
+        download the inputs of every solved day (or the given days) that
+        are not in the inputs directory yet. Cached inputs are never
+        fetched again.
+    watch <day> [input] [--interval MS]
+        whenever the day's source, input or example files change, rebuild
+        and run its example tests and solve it, showing which answers
+        changed since the previous run. Files are polled every MS
+        milliseconds (default 500).
+    render <day> [input] --output FILE [--scale N] [--palette LIST]
+        draw the grid of day 4, 6, 8, 10 or 12 with what its solver found
+        marked on top: words, the guard's path, antinodes, trails, regions.
+        The format follows the extension of FILE: .ppm, .png or .svg. Each
+        cell is N pixels wide (default 8). LIST is comma separated #rrggbb
+        colours: background, foreground, then one or more accents.
+    record <day> [input] --output FILE.gif|DIR [--part 1|2] [--every N]
+           [--limit N] [--scale N] [--delay CS] [--palette LIST]
+        record the steps of a simulation: the guard's patrol of day 6, the
+        compaction of day 9 (part 1 or 2) or the blinks of day 11. A .gif
+        output is an animation showing each frame for CS hundredths of a
+        second (default 5); anything else is a directory that gets numbered
+        PNG frames. Every Nth step is kept (default 1), up to --limit frames
+        (default 1000) plus the final state. --scale and --palette are as
+        for render, with cells 4 pixels wide by default.
+    explore <day> [input] [--palette LIST]
+        step forwards and backwards through the guard's patrol of day 6 or
+        the garden regions of day 12 in the terminal, panning and zooming
+        over the map and toggling its overlays. Keys are listed at the
+        bottom of the screen.
+
+environment:
+    AOC_INPUT_DIR    directory holding the dayXX.txt inputs (default: inputs/)
+    AOC_SESSION      session cookie of adventofcode.com, used by fetch
+    AOC_BASE_URL     server to fetch from (default: https://adventofcode.com)";
+
+/// Why the runner gave up.
+enum CliError {
+    /// The command line itself was wrong, so the usage is worth repeating.
+    Usage(String),
+    /// The command was understood but could not be carried out.
+    Failed(String),
+}
+
+impl From<String> for CliError {
+    fn from(e: String) -> Self {
+        CliError::Usage(e)
+    }
+}
+
+impl From<&str> for CliError {
+    fn from(e: &str) -> Self {
+        CliError::Usage(e.to_owned())
+    }
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match run(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(CliError::Usage(e)) => {
+            eprintln!("error: {e}\n\n{USAGE}");
+            ExitCode::from(2)
+        }
+        Err(CliError::Failed(e)) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: &[String]) -> Result<(), CliError> {
+    let Some((command, rest)) = args.split_first() else {
+        return Err("missing command".into());
+    };
+    match command.as_str() {
+        "run" => run::run(rest),
+        "verify" => verify::verify(rest),
+        "bench" => bench::bench(rest),
+        "new" => new::new(rest),
+        "gen" => gen::gen(rest),
+        "fetch" => fetch::fetch(rest),
+        "watch" => watch::watch(rest),
+        "render" => render::render(rest),
+        "record" => record::record(rest),
+        "explore" => explore::explore(rest),
+        _ => Err(format!("unknown command {command:?}").into()),
+    }
+}
+
+fn find_solver(day: u8) -> Result<Solver, CliError> {
+    solution::solver(day).ok_or_else(|| CliError::Failed(format!("day {day} is not solved yet")))
+}
+
+fn read_input(source: &Source) -> Result<String, CliError> {
+    source
+        .read()
+        .map_err(|e| CliError::Failed(format!("unable to read {source}: {e}")))
+}
+
+/// Reads `source` and solves `parts` of `day` from it, timing each step.
+fn run_solver(day: u8, source: &Source, parts: &[Part]) -> Result<Run, CliError> {
+    let solver = find_solver(day)?;
+    let contents = read_input(source)?;
+    solver
+        .run(&contents, parts)
+        .map_err(|e| CliError::Failed(e.in_file(source.to_string()).to_string()))
+}
+
+/// Reads `source` and solves `parts` of `day` from it.
+fn solve(day: u8, source: &Source, parts: &[Part]) -> Result<Vec<Answer>, CliError> {
+    let run = run_solver(day, source, parts)?;
+    Ok(run.parts.into_iter().map(|part| part.answer).collect())
+}
+
+/// Parses the day arguments shared by the commands working on many days,
+/// defaulting to every solved day.
+fn days_or_all(days: Vec<u8>) -> Vec<u8> {
+    if days.is_empty() {
+        solution::SOLVERS.iter().map(|solver| solver.day).collect()
+    } else {
+        days
+    }
+}
+
+fn parse_day(arg: &str) -> Result<u8, String> {
+    let day = arg
+        .trim_start_matches("day")
+        .parse()
+        .map_err(|_| format!("day must be a number, found {arg:?}"))?;
+    if (1..=25).contains(&day) {
+        Ok(day)
+    } else {
+        Err(format!("day must be between 1 and 25, found {day}"))
+    }
+}
@@ -0,0 +1,125 @@
+//! `aoc new`: scaffold a new day from the `src/dayXX.rs` template.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::{parse_day, CliError};
+
+const TEMPLATE: &str = include_str!("../../dayXX.rs");
+
+pub fn new(args: &[String]) -> Result<(), CliError> {
+    let [day] = args else {
+        return Err("new takes exactly one day".into());
+    };
+    let day = parse_day(day)?;
+    scaffold(Path::new(env!("CARGO_MANIFEST_DIR")), day).map_err(CliError::Failed)
+}
+
+fn scaffold(root: &Path, day: u8) -> Result<(), String> {
+    let module = root.join(format!("src/days/day{day:02}.rs"));
+    if module.exists() {
+        return Err(format!("{} already exists", module.display()));
+    }
+
+    let mod_rs = root.join("src/days/mod.rs");
+    let solution_rs = root.join("src/solution.rs");
+    let registered_mod = register_module(&read(&mod_rs)?, day)?;
+    let registered_solver = register_solver(&read(&solution_rs)?, day)?;
+
+    write(&module, &render(day))?;
+    write(&mod_rs, &registered_mod)?;
+    write(&solution_rs, &registered_solver)?;
+    // the input is left to `aoc fetch`, which would take an empty placeholder
+    // for a downloaded input
+    let example = root.join(format!("inputs/examples/day{day:02}.txt"));
+    if !example.exists() {
+        write(&example, "")?;
+    }
+
+    println!("created {}", module.display());
+    println!("paste the example into inputs/examples/day{day:02}.txt and run `aoc fetch {day}` (or save the puzzle input as inputs/day{day:02}.txt)");
+    Ok(())
+}
+
+fn read(path: &PathBuf) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("unable to read {}: {e}", path.display()))
+}
+
+fn write(path: &PathBuf, contents: &str) -> Result<(), String> {
+    fs::write(path, contents).map_err(|e| format!("unable to write {}: {e}", path.display()))
+}
+
+/// The template with every `XX` placeholder filled in for `day`.
+fn render(day: u8) -> String {
+    TEMPLATE
+        .replace("u8 = XX;", &format!("u8 = {day};"))
+        .replace("XX", &format!("{day:02}"))
+}
+
+/// Adds `pub mod dayNN;` to the days module, keeping the declarations sorted.
+fn register_module(mod_rs: &str, day: u8) -> Result<String, String> {
+    let line = format!("pub mod day{day:02};");
+    insert_sorted(mod_rs, "pub mod day", "", &line)
+        .ok_or_else(|| "unable to find the day modules in src/days/mod.rs".to_owned())
+}
+
+/// Adds the day to the `SOLVERS` registry, keeping it sorted by day.
+fn register_solver(solution_rs: &str, day: u8) -> Result<String, String> {
+    let line = format!("    Solver::of::<day{day:02}::Day{day:02}>(),");
+    insert_sorted(solution_rs, "    Solver::of::<day", "];", &line)
+        .ok_or_else(|| "unable to find the SOLVERS registry in src/solution.rs".to_owned())
+}
+
+/// Inserts `line` among the consecutive lines starting with `prefix`, before
+/// the first one that sorts after it. `end` must be the line closing the block
+/// when it is not simply followed by another kind of line.
+fn insert_sorted(contents: &str, prefix: &str, end: &str, line: &str) -> Option<String> {
+    let mut lines: Vec<&str> = contents.lines().collect();
+    let first = lines.iter().position(|l| l.starts_with(prefix))?;
+    let block = lines[first..]
+        .iter()
+        .take_while(|l| l.starts_with(prefix) && **l != end)
+        .count();
+    let at = lines[first..first + block]
+        .iter()
+        .position(|l| *l > line)
+        .map_or(first + block, |i| first + i);
+    lines.insert(at, line);
+
+    let mut result = lines.join("\n");
+    result.push('\n');
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_fills_in_the_day() {
+        let module = render(7);
+        assert!(module.contains("pub struct Day07;"));
+        assert!(module.contains("const DAY: u8 = 7;"));
+        assert!(module.contains("inputs/examples/day07.txt"));
+        assert!(!module.contains("XX"));
+    }
+
+    #[test]
+    fn registration_keeps_days_sorted() {
+        let mod_rs = "//! Days.\n\npub mod day01;\npub mod day03;\n";
+        assert_eq!(
+            register_module(mod_rs, 2).unwrap(),
+            "//! Days.\n\npub mod day01;\npub mod day02;\npub mod day03;\n"
+        );
+        assert_eq!(
+            register_module(mod_rs, 13).unwrap(),
+            "//! Days.\n\npub mod day01;\npub mod day03;\npub mod day13;\n"
+        );
+
+        let solution_rs = "const SOLVERS = &[\n    Solver::of::<day01::Day01>(),\n];\n";
+        assert_eq!(
+            register_solver(solution_rs, 2).unwrap(),
+            "const SOLVERS = &[\n    Solver::of::<day01::Day01>(),\n    Solver::of::<day02::Day02>(),\n];\n"
+        );
+    }
+}
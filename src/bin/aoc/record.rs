@@ -0,0 +1,86 @@
+//! `aoc record`: record the steps of a simulation day as an animation.
+
+use std::path::PathBuf;
+
+use aoc::input::Source;
+use aoc::record::{self, FrameDir, GifWriter, Recorder, Sink};
+use aoc::render::Palette;
+use aoc::solution::Part;
+
+use crate::{parse_day, read_input, CliError};
+
+pub fn record(args: &[String]) -> Result<(), CliError> {
+    let mut day = None;
+    let mut path = None;
+    let mut part = Part::One;
+    let mut output = None;
+    let mut every = 1;
+    let mut limit = 1000;
+    let mut scale = 4;
+    let mut delay = 5;
+    let mut palette = Palette::default();
+
+    let positive = |flag: &str, n: Option<&String>| -> Result<usize, CliError> {
+        let n = n.ok_or_else(|| format!("{flag} needs a value"))?;
+        let value = n.parse().ok().filter(|&n| n > 0);
+        Ok(value.ok_or_else(|| format!("{flag} must be a positive number, found {n:?}"))?)
+    };
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => part = args.next().ok_or("--part needs a value")?.parse()?,
+            "--output" | "-o" => {
+                output = Some(PathBuf::from(args.next().ok_or("--output needs a path")?));
+            }
+            "--every" => every = positive("--every", args.next())?,
+            "--limit" => limit = positive("--limit", args.next())?,
+            "--scale" => scale = positive("--scale", args.next())?,
+            "--delay" => {
+                let cs = args.next().ok_or("--delay needs hundredths of a second")?;
+                delay = cs
+                    .parse()
+                    .map_err(|_| format!("delay must be hundredths of a second, found {cs:?}"))?;
+            }
+            "--palette" => {
+                palette = Palette::parse(args.next().ok_or("--palette needs colours")?)?;
+            }
+            _ if day.is_none() => day = Some(parse_day(arg)?),
+            _ if path.is_none() => path = Some(arg.as_str()),
+            _ => return Err(format!("unexpected argument {arg:?}").into()),
+        }
+    }
+
+    let day = day.ok_or("missing day")?;
+    let output = output.ok_or("missing --output path")?;
+    let source = Source::from_arg(day, path);
+    let contents = read_input(&source)?;
+    let failed =
+        |e: std::io::Error| CliError::Failed(format!("unable to write {}: {e}", output.display()));
+    let is_gif = output
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("gif"));
+    let (mut gif, mut dir) = (None, None);
+    let sink: &mut dyn Sink = if is_gif {
+        gif.insert(GifWriter::new(&output, scale, delay))
+    } else {
+        dir.insert(FrameDir::create(&output, scale).map_err(failed)?)
+    };
+
+    let mut recorder = Recorder::new(every, limit, sink);
+    record::record(day, part, &contents, &palette, &mut recorder)
+        .ok_or_else(|| {
+            CliError::Failed(format!("day {day} part {part} has no simulation to record"))
+        })?
+        .map_err(|e| CliError::Failed(e.in_file(source.to_string()).to_string()))?;
+    recorder.result().map_err(failed)?;
+    let (frames, steps) = (recorder.frames(), recorder.steps());
+    if let Some(gif) = gif {
+        gif.finish().map_err(failed)?;
+    }
+    println!(
+        "day {day} part {part}: {frames} frames of {steps} steps in {}",
+        output.display()
+    );
+    Ok(())
+}
@@ -0,0 +1,55 @@
+//! `aoc render`: draw a grid day's input and what its solver found on it.
+
+use std::path::PathBuf;
+
+use aoc::input::Source;
+use aoc::render::{self, Palette};
+
+use crate::{parse_day, read_input, CliError};
+
+pub fn render(args: &[String]) -> Result<(), CliError> {
+    let mut day = None;
+    let mut path = None;
+    let mut output = None;
+    let mut scale = 8;
+    let mut palette = Palette::default();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--output" | "-o" => {
+                output = Some(PathBuf::from(args.next().ok_or("--output needs a file")?));
+            }
+            "--scale" => {
+                let n = args.next().ok_or("--scale needs a value")?;
+                scale = n
+                    .parse()
+                    .ok()
+                    .filter(|&n| n > 0)
+                    .ok_or_else(|| format!("scale must be a positive number, found {n:?}"))?;
+            }
+            "--palette" => {
+                palette = Palette::parse(args.next().ok_or("--palette needs colours")?)?;
+            }
+            _ if day.is_none() => day = Some(parse_day(arg)?),
+            _ if path.is_none() => path = Some(arg.as_str()),
+            _ => return Err(format!("unexpected argument {arg:?}").into()),
+        }
+    }
+
+    let day = day.ok_or("missing day")?;
+    let output = output.ok_or("missing --output file")?;
+    if render::Format::of(&output).is_none() {
+        return Err("the output file must end in .ppm, .png or .svg".into());
+    }
+    let source = Source::from_arg(day, path);
+    let contents = read_input(&source)?;
+    let picture = render::render(day, &contents, &palette)
+        .ok_or_else(|| CliError::Failed(format!("day {day} has nothing to render")))?
+        .map_err(|e| CliError::Failed(e.in_file(source.to_string()).to_string()))?;
+    picture
+        .save(&output, scale)
+        .map_err(|e| CliError::Failed(format!("unable to write {}: {e}", output.display())))?;
+    println!("day {day}: {}", output.display());
+    Ok(())
+}
@@ -0,0 +1,192 @@
+//! `aoc run`: solve days and print their answers.
+
+use std::time::Instant;
+
+use aoc::input::Source;
+use aoc::solution::{self, Decision, Part, PartRun, SolveError};
+use rayon::prelude::*;
+use serde::Serialize;
+
+use crate::summary::{self, DayOutcome};
+use crate::{find_solver, parse_day, read_input, run_solver, CliError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Text,
+    Json,
+}
+
+/// One line of `--format json` output.
+#[derive(Serialize)]
+struct JsonAnswer<'a> {
+    day: u8,
+    part: u8,
+    answer: &'a str,
+    elapsed_ns: u64,
+    /// `null` when the input came from stdin.
+    input: Option<String>,
+}
+
+/// One line of `--explain --format json` output.
+#[derive(Serialize)]
+struct JsonDecision<'a> {
+    day: u8,
+    part: u8,
+    item: &'a str,
+    verdict: &'a str,
+    contribution: u64,
+}
+
+pub fn run(args: &[String]) -> Result<(), CliError> {
+    let mut days = None;
+    let mut path = None;
+    let mut parts = Part::ALL.to_vec();
+    let mut format = Format::Text;
+    let mut parallel = false;
+    let mut explain = false;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" | "-p" => {
+                let part = args.next().ok_or("--part needs a value")?;
+                parts = vec![part.parse()?];
+            }
+            "--format" => {
+                format = match args.next().map(String::as_str) {
+                    Some("text") => Format::Text,
+                    Some("json") => Format::Json,
+                    _ => return Err("--format must be text or json".into()),
+                };
+            }
+            "--parallel" | "-j" => parallel = true,
+            "--explain" => explain = true,
+            "all" if days.is_none() => {
+                days = Some(solution::SOLVERS.iter().map(|s| s.day).collect());
+            }
+            _ if days.is_none() => days = Some(vec![parse_day(arg)?]),
+            _ if path.is_none() => path = Some(arg.as_str()),
+            _ => return Err(format!("unexpected argument {arg:?}").into()),
+        }
+    }
+
+    let days: Vec<u8> = days.ok_or("missing day")?;
+    let many = days.len() > 1;
+    if many && path.is_some() {
+        return Err("an input can only be given when running a single day".into());
+    }
+    if many && explain {
+        return Err("--explain works on a single day".into());
+    }
+
+    if explain {
+        return run_explained(days[0], &Source::from_arg(days[0], path), &parts, format);
+    }
+
+    if !many {
+        let source = Source::from_arg(days[0], path);
+        let run = run_solver(days[0], &source, &parts)?;
+        for part in &run.parts {
+            match format {
+                Format::Text => println!("{}", text(part)),
+                Format::Json => println!("{}", json(days[0], &source, part)),
+            }
+        }
+        return Ok(());
+    }
+
+    let start = Instant::now();
+    let solve = |&day: &u8| DayOutcome {
+        day,
+        result: run_solver(day, &Source::Day(day), &parts).map_err(|e| match e {
+            CliError::Usage(e) | CliError::Failed(e) => e,
+        }),
+    };
+    let outcomes: Vec<DayOutcome> = if parallel {
+        days.par_iter().map(solve).collect()
+    } else {
+        days.iter().map(solve).collect()
+    };
+    let wall = start.elapsed();
+
+    match format {
+        Format::Text => print!("{}", summary::table(&outcomes, wall)),
+        Format::Json => {
+            for outcome in &outcomes {
+                match &outcome.result {
+                    Ok(run) => {
+                        let source = Source::Day(outcome.day);
+                        for part in &run.parts {
+                            println!("{}", json(outcome.day, &source, part));
+                        }
+                    }
+                    Err(e) => eprintln!("day {}: {e}", outcome.day),
+                }
+            }
+        }
+    }
+
+    let failed = outcomes.iter().filter(|o| o.result.is_err()).count();
+    if failed > 0 {
+        return Err(CliError::Failed(format!("{failed} day(s) failed")));
+    }
+    Ok(())
+}
+
+/// Prints every decision behind each part before its answer.
+fn run_explained(day: u8, source: &Source, parts: &[Part], format: Format) -> Result<(), CliError> {
+    let solver = find_solver(day)?;
+    let contents = read_input(source)?;
+    let failed = |e: SolveError| CliError::Failed(e.in_file(source.to_string()).to_string());
+    let run = solver.run(&contents, parts).map_err(failed)?;
+    for part in &run.parts {
+        let decisions = solver
+            .explain(&contents, part.answer.part)
+            .map_err(|e| failed(e.into()))?
+            .ok_or_else(|| CliError::Failed(format!("day {day} cannot explain its answers")))?;
+        for decision in &decisions {
+            match format {
+                Format::Text => println!("{}", explained(decision)),
+                Format::Json => println!("{}", json_decision(day, part.answer.part, decision)),
+            }
+        }
+        match format {
+            Format::Text => println!("{}", text(part)),
+            Format::Json => println!("{}", json(day, source, part)),
+        }
+    }
+    Ok(())
+}
+
+fn explained(decision: &Decision) -> String {
+    format!(
+        "{} -> {} (+{})",
+        decision.item, decision.verdict, decision.contribution
+    )
+}
+
+fn json_decision(day: u8, part: Part, decision: &Decision) -> String {
+    let line = JsonDecision {
+        day,
+        part: part.number(),
+        item: &decision.item,
+        verdict: &decision.verdict,
+        contribution: decision.contribution,
+    };
+    serde_json::to_string(&line).expect("decisions always serialize")
+}
+
+fn text(part: &PartRun) -> String {
+    format!("Part {} = {}", part.answer.part, part.answer.value)
+}
+
+fn json(day: u8, source: &Source, part: &PartRun) -> String {
+    let line = JsonAnswer {
+        day,
+        part: part.answer.part.number(),
+        answer: &part.answer.value,
+        elapsed_ns: part.elapsed.as_nanos() as u64,
+        input: source.path().map(|path| path.display().to_string()),
+    };
+    serde_json::to_string(&line).expect("answers always serialize")
+}
@@ -0,0 +1,130 @@
+//! The table `aoc run all` prints once every day has been solved.
+
+use std::fmt::Write;
+use std::time::Duration;
+
+use aoc::solution::Run;
+
+use crate::bench::format_duration;
+
+/// How many of the slowest parts get flagged in the table.
+const SLOWEST: usize = 3;
+
+/// What came of solving one day.
+pub struct DayOutcome {
+    pub day: u8,
+    pub result: Result<Run, String>,
+}
+
+/// Renders one row per part solved plus a row per failed day, followed by the
+/// totals. The slowest parts are marked so they stand out.
+pub fn table(outcomes: &[DayOutcome], wall: Duration) -> String {
+    let mut times: Vec<Duration> = outcomes
+        .iter()
+        .filter_map(|o| o.result.as_ref().ok())
+        .flat_map(|run| run.parts.iter().map(|part| part.elapsed))
+        .collect();
+    times.sort_unstable_by(|a, b| b.cmp(a));
+    let slow = times.get(SLOWEST - 1).or(times.last()).copied();
+
+    let width = outcomes
+        .iter()
+        .filter_map(|o| o.result.as_ref().ok())
+        .flat_map(|run| run.parts.iter().map(|part| part.answer.value.len()))
+        .fold("answer".len(), usize::max);
+
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        "day  part  {:>width$}  {:>10}  {:>10}",
+        "answer", "parse", "time"
+    );
+    let (mut parse, mut solve, mut parts, mut failed) = (Duration::ZERO, Duration::ZERO, 0, 0);
+    for outcome in outcomes {
+        let run = match &outcome.result {
+            Ok(run) => run,
+            Err(e) => {
+                let _ = writeln!(out, "{:>3}  FAILED {e}", outcome.day);
+                failed += 1;
+                continue;
+            }
+        };
+        parse += run.parse;
+        for part in &run.parts {
+            let marker = match slow {
+                Some(slow) if part.elapsed >= slow => "  <- slowest",
+                _ => "",
+            };
+            let _ = writeln!(
+                out,
+                "{:>3}  {:>4}  {:>width$}  {:>10}  {:>10}{marker}",
+                outcome.day,
+                part.answer.part.number(),
+                part.answer.value,
+                format_duration(run.parse),
+                format_duration(part.elapsed),
+            );
+            solve += part.elapsed;
+            parts += 1;
+        }
+    }
+
+    let _ = writeln!(
+        out,
+        "\n{parts} part(s) solved in {} (parsing {}), {} wall clock",
+        format_duration(parse + solve),
+        format_duration(parse),
+        format_duration(wall),
+    );
+    if failed > 0 {
+        let _ = writeln!(out, "{failed} day(s) FAILED");
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::solution::{Answer, Part, PartRun};
+
+    fn run(parts: &[(Part, u64, u64)]) -> Result<Run, String> {
+        Ok(Run {
+            parse: Duration::from_millis(1),
+            parts: parts
+                .iter()
+                .map(|&(part, answer, ms)| PartRun {
+                    answer: Answer::new(part, answer),
+                    elapsed: Duration::from_millis(ms),
+                })
+                .collect(),
+        })
+    }
+
+    #[test]
+    fn flags_slowest_parts_and_failures() {
+        let outcomes = [
+            DayOutcome {
+                day: 1,
+                result: run(&[(Part::One, 11, 1), (Part::Two, 31, 2)]),
+            },
+            DayOutcome {
+                day: 2,
+                result: Err("no input".to_owned()),
+            },
+            DayOutcome {
+                day: 3,
+                result: run(&[(Part::One, 161, 50), (Part::Two, 48, 40)]),
+            },
+        ];
+        let table = table(&outcomes, Duration::from_millis(100));
+        let slowest: Vec<&str> = table
+            .lines()
+            .filter(|line| line.ends_with("<- slowest"))
+            .collect();
+        assert_eq!(slowest.len(), 3);
+        assert!(!slowest.iter().any(|line| line.contains(" 11 ")));
+        assert!(table.contains("  2  FAILED no input"));
+        assert!(table.contains("4 part(s) solved in 95.00ms (parsing 2.00ms)"));
+        assert!(table.contains("1 day(s) FAILED"));
+    }
+}
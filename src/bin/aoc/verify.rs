@@ -0,0 +1,65 @@
+//! `aoc verify`: check every day against the recorded answers.
+
+use aoc::answers::{self, Answers, Check};
+use aoc::input::Source;
+use aoc::solution::Part;
+
+use crate::{days_or_all, parse_day, solve, CliError};
+
+pub fn verify(args: &[String]) -> Result<(), CliError> {
+    let mut days = Vec::new();
+    let mut record = false;
+    for arg in args {
+        match arg.as_str() {
+            "--record" => record = true,
+            _ => days.push(parse_day(arg)?),
+        }
+    }
+    let days = days_or_all(days);
+
+    let path = answers::path();
+    let mut recorded = Answers::load(&path)
+        .map_err(|e| CliError::Failed(format!("unable to load {}: {e}", path.display())))?;
+
+    let mut failures = 0;
+    let mut newly_recorded = 0;
+    for day in days {
+        let answers = match solve(day, &Source::Day(day), &Part::ALL) {
+            Ok(answers) => answers,
+            Err(CliError::Usage(e) | CliError::Failed(e)) => {
+                println!("day {day:>2}: FAILED {e}");
+                failures += 1;
+                continue;
+            }
+        };
+        for answer in answers {
+            let label = format!("day {day:>2} part {}", answer.part);
+            match recorded.check(day, &answer) {
+                Check::Match => println!("{label}: ok"),
+                Check::Mismatch { expected } => {
+                    println!(
+                        "{label}: MISMATCH expected {expected}, got {}",
+                        answer.value
+                    );
+                    failures += 1;
+                }
+                Check::Unrecorded if record => {
+                    println!("{label}: recorded {}", answer.value);
+                    recorded.record(day, &answer);
+                    newly_recorded += 1;
+                }
+                Check::Unrecorded => println!("{label}: no recorded answer ({})", answer.value),
+            }
+        }
+    }
+
+    if newly_recorded > 0 {
+        recorded
+            .save(&path)
+            .map_err(|e| CliError::Failed(format!("unable to save {}: {e}", path.display())))?;
+    }
+    if failures > 0 {
+        return Err(CliError::Failed(format!("{failures} check(s) failed")));
+    }
+    Ok(())
+}
@@ -0,0 +1,235 @@
+//! `aoc watch`: re-run a day whenever its source, input or examples change.
+//!
+//! Source edits need a rebuild, so rather than solving in-process this drives
+//! cargo: the day's example tests, then `aoc run --format json` built from the
+//! current tree. Files are polled for changes to keep the runner free of
+//! platform specific watchers.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use aoc::input::Source;
+use serde::Deserialize;
+
+use crate::{parse_day, CliError};
+
+/// Answers of one run, by part number.
+type Answers = BTreeMap<u8, String>;
+
+/// The fields of a `run --format json` line the watcher cares about.
+#[derive(Deserialize)]
+struct Reported {
+    part: u8,
+    answer: String,
+}
+
+pub fn watch(args: &[String]) -> Result<(), CliError> {
+    let mut day = None;
+    let mut path = None;
+    let mut interval = Duration::from_millis(500);
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--interval" => {
+                let ms = args.next().ok_or("--interval needs milliseconds")?;
+                let ms = ms
+                    .parse()
+                    .map_err(|_| format!("interval must be milliseconds, found {ms:?}"))?;
+                interval = Duration::from_millis(ms);
+            }
+            _ if day.is_none() => day = Some(parse_day(arg)?),
+            _ if path.is_none() => path = Some(arg.as_str()),
+            _ => return Err(format!("unexpected argument {arg:?}").into()),
+        }
+    }
+    let day = day.ok_or("missing day")?;
+    if path == Some("-") {
+        return Err("watch cannot read the input from stdin".into());
+    }
+
+    let path = path.map(resolve).transpose()?;
+
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let source = match &path {
+        Some(path) => Source::Path(path.clone()),
+        None => Source::Day(day),
+    };
+    let files = watched_files(root, day, &source);
+    println!("watching day {day}:");
+    for file in &files {
+        println!("    {}", file.display());
+    }
+
+    let mut seen = modified(&files);
+    let mut previous = None;
+    loop {
+        previous = Some(check(root, day, path.as_deref(), previous.as_ref()));
+        println!("\nwaiting for changes (Ctrl-C to stop)");
+        loop {
+            thread::sleep(interval);
+            let now = modified(&files);
+            if now != seen {
+                seen = now;
+                break;
+            }
+        }
+    }
+}
+
+/// `path` made absolute, so the file watched is the one solved even though
+/// cargo runs the solver from the repository root. Inputs that do not exist
+/// yet are resolved against the current directory.
+fn resolve(path: &str) -> Result<PathBuf, String> {
+    fs::canonicalize(path)
+        .or_else(|_| std::path::absolute(path))
+        .map_err(|e| format!("unable to resolve {path}: {e}"))
+}
+
+/// The day's module, its input and every example fixture for it.
+fn watched_files(root: &Path, day: u8, source: &Source) -> Vec<PathBuf> {
+    let mut files = vec![root.join(format!("src/days/day{day:02}.rs"))];
+    files.extend(source.path());
+    let examples = root.join("inputs/examples");
+    let prefix = format!("day{day:02}");
+    if let Ok(entries) = fs::read_dir(&examples) {
+        let mut fixtures: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| {
+                path.file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| name.starts_with(&prefix) && name.ends_with(".txt"))
+            })
+            .collect();
+        fixtures.sort();
+        files.extend(fixtures);
+    }
+    files
+}
+
+/// When each file was last modified; files that do not exist yet count too,
+/// so creating one is noticed.
+fn modified(files: &[PathBuf]) -> Vec<Option<SystemTime>> {
+    files
+        .iter()
+        .map(|file| fs::metadata(file).and_then(|m| m.modified()).ok())
+        .collect()
+}
+
+/// Runs the example tests and the solver once, printing how the answers
+/// compare with `previous`. Returns the new answers, or the previous ones if
+/// the run failed so the next diff is against the last good run.
+fn check(root: &Path, day: u8, path: Option<&Path>, previous: Option<&Answers>) -> Answers {
+    println!("\n=== day {day} ===");
+    let tests = cargo(root)
+        .args(["test", "--quiet", "--lib", "--"])
+        .arg(format!("days::day{day:02}::"))
+        .status();
+    match tests {
+        Ok(status) if status.success() => println!("examples: ok"),
+        Ok(_) => println!("examples: FAILED"),
+        Err(e) => println!("examples: unable to run cargo: {e}"),
+    }
+
+    let mut run = cargo(root);
+    run.args(["run", "--quiet", "--release", "--", "run"])
+        .arg(day.to_string())
+        .args(path)
+        .args(["--format", "json"]);
+    let output = match run.output() {
+        Ok(output) => output,
+        Err(e) => {
+            println!("run: unable to run cargo: {e}");
+            return previous.cloned().unwrap_or_default();
+        }
+    };
+    if !output.status.success() {
+        print!("{}", String::from_utf8_lossy(&output.stderr));
+        return previous.cloned().unwrap_or_default();
+    }
+
+    let answers = parse_answers(&String::from_utf8_lossy(&output.stdout));
+    for line in diff(previous, &answers) {
+        println!("{line}");
+    }
+    answers
+}
+
+fn cargo(root: &Path) -> Command {
+    let mut command = Command::new(std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into()));
+    command.current_dir(root);
+    command
+}
+
+fn parse_answers(json_lines: &str) -> Answers {
+    json_lines
+        .lines()
+        .filter_map(|line| serde_json::from_str::<Reported>(line).ok())
+        .map(|reported| (reported.part, reported.answer))
+        .collect()
+}
+
+/// One line per part describing how its answer moved since `previous`.
+fn diff(previous: Option<&Answers>, current: &Answers) -> Vec<String> {
+    current
+        .iter()
+        .map(|(part, answer)| {
+            let before = previous.and_then(|previous| previous.get(part));
+            match before {
+                None => format!("Part {part} = {answer}"),
+                Some(before) if before == answer => format!("Part {part} = {answer} (unchanged)"),
+                Some(before) => format!("Part {part} = {answer} (CHANGED, was {before})"),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answers_are_read_from_json_lines() {
+        let out = "{\"day\":1,\"part\":1,\"answer\":\"11\",\"elapsed_ns\":5,\"input\":null}\n\
+                   {\"day\":1,\"part\":2,\"answer\":\"31\",\"elapsed_ns\":7,\"input\":null}\n";
+        let answers = parse_answers(out);
+        assert_eq!(answers, Answers::from([(1, "11".into()), (2, "31".into())]));
+    }
+
+    #[test]
+    fn diff_marks_changed_answers() {
+        let before = Answers::from([(1, "11".into()), (2, "31".into())]);
+        let after = Answers::from([(1, "11".into()), (2, "32".into())]);
+        assert_eq!(
+            diff(Some(&before), &after),
+            ["Part 1 = 11 (unchanged)", "Part 2 = 32 (CHANGED, was 31)"]
+        );
+        assert_eq!(diff(None, &after), ["Part 1 = 11", "Part 2 = 32"]);
+    }
+
+    #[test]
+    fn watches_the_module_input_and_examples() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let files = watched_files(root, 3, &Source::Path("alt.txt".into()));
+        let names: Vec<String> = files
+            .iter()
+            .map(|file| file.file_name().unwrap().to_string_lossy().into_owned())
+            .collect();
+        assert_eq!(
+            names,
+            ["day03.rs", "alt.txt", "day03-part2.txt", "day03.txt"]
+        );
+    }
+
+    #[test]
+    fn input_paths_are_resolved_before_cargo_changes_directory() {
+        let cwd = std::env::current_dir().unwrap();
+        assert_eq!(resolve("Cargo.toml"), Ok(cwd.join("Cargo.toml")));
+        assert_eq!(resolve("not-yet.txt"), Ok(cwd.join("not-yet.txt")));
+    }
+}
@@ -1,31 +1,46 @@
-use std::fs::File;
-use std::io::{prelude::*, BufReader};
+use crate::error::{Checked, Result};
+use crate::parse;
+use crate::solution::Solution;
 
-fn main() {
-    part1();
-    part2();
-}
+pub struct DayXX;
+
+impl Solution for DayXX {
+    const DAY: u8 = XX;
 
-fn part1() {
-    let file = File::open("inputs/day03.txt").unwrap();
-    let reader = BufReader::new(file);
+    type Input<'a> = Vec<&'a [u8]>;
+    type Answer1 = u64;
+    type Answer2 = u64;
 
-    let ans = reader
-        .lines()
-        .map(|line| line.unwrap())
-        .fold(0, |acc, _line| acc + 1);
+    fn parse(contents: &str) -> Result<Self::Input<'_>> {
+        parse::each_line(contents.as_bytes(), Ok)
+    }
 
-    println!("Part 1 = {ans}");
+    fn part1(lines: &Self::Input<'_>) -> Checked<u64> {
+        Ok(lines.iter().fold(0, |acc, _line| acc + 1))
+    }
+
+    fn part2(lines: &Self::Input<'_>) -> Checked<u64> {
+        Ok(lines.iter().fold(0, |acc, _line| acc + 1))
+    }
 }
 
-fn part2() {
-    let file = File::open("inputs/day03.txt").unwrap();
-    let reader = BufReader::new(file);
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../inputs/examples/dayXX.txt");
 
-    let ans = reader
-        .lines()
-        .map(|line| line.unwrap())
-        .fold(0, |acc, _line| acc + 1);
+    #[test]
+    #[ignore = "add the example and its answer from the puzzle text"]
+    fn example_part1() {
+        let input = DayXX::parse(EXAMPLE).unwrap();
+        assert_eq!(DayXX::part1(&input), Ok(0));
+    }
 
-    println!("Part 2 = {ans}");
+    #[test]
+    #[ignore = "add the example and its answer from the puzzle text"]
+    fn example_part2() {
+        let input = DayXX::parse(EXAMPLE).unwrap();
+        assert_eq!(DayXX::part2(&input), Ok(0));
+    }
 }
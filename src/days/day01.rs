@@ -0,0 +1,78 @@
+use crate::error::{Checked, Overflow, Result};
+use crate::parse;
+use crate::solution::Solution;
+use std::collections::HashMap;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input<'a> = (Vec<i64>, Vec<i64>);
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(contents: &str) -> Result<Self::Input<'_>> {
+        // one pair per line, split into the left and right lists
+        let pairs = parse::each_line(contents.as_bytes(), |line| parse::pair::<i64>(line, b' '))?;
+        Ok(pairs.into_iter().unzip())
+    }
+
+    fn part1((left, right): &Self::Input<'_>) -> Checked<i64> {
+        let mut left = left.clone();
+        let mut right = right.clone();
+        left.sort();
+        right.sort();
+
+        left.iter()
+            .zip(right.iter())
+            .try_fold(0, |acc: i64, (&a, &b)| {
+                a.checked_sub(b)
+                    .and_then(i64::checked_abs)
+                    .and_then(|distance| acc.checked_add(distance))
+                    .ok_or_else(|| {
+                        Overflow::new(format!(
+                            "adding the distance between {a} and {b} to {acc} overflows i64"
+                        ))
+                    })
+            })
+    }
+
+    fn part2((left, right): &Self::Input<'_>) -> Checked<i64> {
+        // the right list becomes a frequency map
+        let mut frequency: HashMap<i64, i64> = HashMap::with_capacity(right.len());
+        for r in right {
+            *frequency.entry(*r).or_insert(0) += 1;
+        }
+
+        left.iter().try_fold(0, |acc: i64, &x| {
+            let k = *frequency.get(&x).unwrap_or(&0);
+            x.checked_mul(k)
+                .and_then(|score| acc.checked_add(score))
+                .ok_or_else(|| {
+                    Overflow::new(format!(
+                        "adding the score of {x}, seen {k} times, to {acc} overflows i64"
+                    ))
+                })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../inputs/examples/day01.txt");
+
+    #[test]
+    fn example_part1() {
+        let input = Day01::parse(EXAMPLE).unwrap();
+        assert_eq!(Day01::part1(&input), Ok(11));
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day01::parse(EXAMPLE).unwrap();
+        assert_eq!(Day01::part2(&input), Ok(31));
+    }
+}
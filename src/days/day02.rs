@@ -0,0 +1,264 @@
+use crate::error::{Checked, Overflow, ParseError, Result};
+use crate::parse;
+use crate::solution::{Decision, Part, Solution};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input<'a> = Vec<Vec<i64>>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(contents: &str) -> Result<Self::Input<'_>> {
+        parse::each_line(contents.as_bytes(), line_to_levels)
+    }
+
+    fn part1(levels: &Self::Input<'_>) -> Checked<i64> {
+        count_safe(levels, level_safety)
+    }
+
+    fn part2(levels: &Self::Input<'_>) -> Checked<i64> {
+        count_safe(levels, level_safety_tolerance)
+    }
+
+    fn explain(levels: &Self::Input<'_>, part: Part) -> Option<Vec<Decision>> {
+        let decisions = levels.iter().map(|level| {
+            let report = level
+                .iter()
+                .map(|x| x.to_string())
+                .collect::<Vec<_>>()
+                .join(" ");
+            let verdict = match part {
+                Part::One => explain_safety(level),
+                Part::Two => explain_safety_tolerance(level),
+            };
+            match verdict {
+                Ok(verdict) => Decision::new(report, verdict, 1),
+                Err(verdict) => Decision::new(report, verdict, 0),
+            }
+        });
+        Some(decisions.collect())
+    }
+}
+
+/// Why a report is safe, or why not.
+/// The number of reports `safety` counts as safe.
+fn count_safe(levels: &[Vec<i64>], safety: fn(&[i64]) -> i64) -> Checked<i64> {
+    levels
+        .iter()
+        .enumerate()
+        .try_fold(0, |acc: i64, (i, level)| {
+            acc.checked_add(safety(level)).ok_or_else(|| {
+                Overflow::new(format!(
+                    "counting report {} as safe after {acc} others overflows i64",
+                    i + 1
+                ))
+            })
+        })
+}
+
+fn explain_safety(level: &[i64]) -> std::result::Result<String, String> {
+    let sign = if level[0] < level[1] { 1 } else { -1 };
+    let direction = if sign == 1 {
+        "increasing"
+    } else {
+        "decreasing"
+    };
+    let Some(pair) = level.windows(2).find(|p| !pair_is_safe(p[0], p[1], sign)) else {
+        return Ok(format!("safe, {direction} by 1 to 3"));
+    };
+    let (x, y) = (pair[0], pair[1]);
+    let reason = if x == y {
+        "neither increasing nor decreasing".to_owned()
+    } else if (y > x) != (sign == 1) {
+        format!("turns around after {direction}")
+    } else {
+        format!("changes by {}", y.abs_diff(x))
+    };
+    Err(format!("unsafe, {x} {y} {reason}"))
+}
+
+/// Why a report is safe with the problem dampener, naming the level removed
+/// if one had to be.
+fn explain_safety_tolerance(level: &[i64]) -> std::result::Result<String, String> {
+    if let Ok(verdict) = explain_safety(level) {
+        return Ok(verdict);
+    }
+    let removable = (0..level.len()).find(|&skip| {
+        [1, -1]
+            .into_iter()
+            .any(|sign| is_safe_without(level, skip, sign))
+    });
+    match removable {
+        Some(skip) => Ok(format!(
+            "safe after removing level {} ({})",
+            skip + 1,
+            level[skip]
+        )),
+        None => Err("unsafe, even with any one level removed".to_owned()),
+    }
+}
+
+fn line_to_levels(line: &[u8]) -> Result<Vec<i64>> {
+    let levels = parse::integers(line, b' ')?;
+    if levels.len() < 2 {
+        return Err(ParseError::in_line(
+            line,
+            line,
+            "a report needs at least two levels",
+        ));
+    }
+    Ok(levels)
+}
+
+fn level_safety(level: &[i64]) -> i64 {
+    let sign = if level[0] < level[1] { 1 } else { -1 };
+
+    for pair in level.windows(2) {
+        if !pair_is_safe(pair[0], pair[1], sign) {
+            return 0;
+        }
+    }
+    1
+}
+
+fn pair_is_safe(x: i64, y: i64, sign: i64) -> bool {
+    // a step too big for i64 is certainly too big to be safe
+    let Some(abs) = y.checked_sub(x).and_then(|diff| diff.checked_mul(sign)) else {
+        return false;
+    };
+    abs > 0 && abs < 4
+}
+
+/// Whether the report is safe with at most one level removed.
+///
+/// Only the two levels of the first unsafe pair are worth removing, checked
+/// for both directions. This used to guess the direction by majority vote and
+/// patch up sliding windows of three levels, which wrongly rejected reports
+/// such as `0 1 1` and `0 1 0 2`.
+fn level_safety_tolerance(level: &[i64]) -> i64 {
+    // a single bad level can make either direction look like the majority,
+    // so try both
+    let safe = [1, -1]
+        .into_iter()
+        .any(|sign| is_safe_with_tolerance(level, sign));
+    i64::from(safe)
+}
+
+fn is_safe_with_tolerance(level: &[i64], sign: i64) -> bool {
+    // find the first unsafe pair, one of its two levels has to go
+    let Some(i) = (0..level.len() - 1).find(|&i| !pair_is_safe(level[i], level[i + 1], sign))
+    else {
+        return true;
+    };
+    [i, i + 1]
+        .into_iter()
+        .any(|skip| is_safe_without(level, skip, sign))
+}
+
+fn is_safe_without(level: &[i64], skip: usize, sign: i64) -> bool {
+    let mut rest = level
+        .iter()
+        .enumerate()
+        .filter(|&(i, _)| i != skip)
+        .map(|(_, &x)| x);
+    let Some(mut prev) = rest.next() else {
+        return true;
+    };
+    rest.all(|x| pair_is_safe(std::mem::replace(&mut prev, x), x, sign))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../../inputs/examples/day02.txt");
+
+    #[test]
+    fn example_part1() {
+        let input = Day02::parse(EXAMPLE).unwrap();
+        assert_eq!(Day02::part1(&input), Ok(2));
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day02::parse(EXAMPLE).unwrap();
+        assert_eq!(Day02::part2(&input), Ok(4));
+    }
+
+    #[test]
+    fn example_explained() {
+        let input = Day02::parse(EXAMPLE).unwrap();
+        let verdicts = |part| -> Vec<String> {
+            let decisions = Day02::explain(&input, part).unwrap();
+            decisions.into_iter().map(|d| d.verdict).collect()
+        };
+        assert_eq!(
+            verdicts(Part::One),
+            [
+                "safe, decreasing by 1 to 3",
+                "unsafe, 2 7 changes by 5",
+                "unsafe, 6 2 changes by 4",
+                "unsafe, 3 2 turns around after increasing",
+                "unsafe, 4 4 neither increasing nor decreasing",
+                "safe, increasing by 1 to 3",
+            ]
+        );
+        assert_eq!(
+            verdicts(Part::Two)[1..5],
+            [
+                "unsafe, even with any one level removed",
+                "unsafe, even with any one level removed",
+                "safe after removing level 2 (3)",
+                "safe after removing level 3 (4)",
+            ]
+        );
+    }
+
+    /// Reference answer: a report is safe if it is strictly monotonic with
+    /// steps of at most three.
+    fn naive_is_safe(levels: &[i64]) -> bool {
+        let steps = || levels.windows(2).map(|w| w[1] - w[0]);
+        steps().all(|d| (1..=3).contains(&d)) || steps().all(|d| (-3..=-1).contains(&d))
+    }
+
+    /// Reference answer for part 2: try removing every level in turn.
+    fn naive_is_safe_with_tolerance(levels: &[i64]) -> bool {
+        naive_is_safe(levels)
+            || (0..levels.len()).any(|i| {
+                let mut levels = levels.to_vec();
+                levels.remove(i);
+                naive_is_safe(&levels)
+            })
+    }
+
+    /// Reports that wander up and down by small steps, so both safe and
+    /// almost safe ones come up often.
+    fn reports() -> impl Strategy<Value = Vec<i64>> {
+        (0..100i64, prop::collection::vec(-5..=5i64, 1..8)).prop_map(|(start, steps)| {
+            let rest = steps.into_iter().scan(start, |level, step| {
+                *level += step;
+                Some(*level)
+            });
+            std::iter::once(start).chain(rest).collect()
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn safety_matches_naive(levels in reports()) {
+            prop_assert_eq!(level_safety(&levels) == 1, naive_is_safe(&levels));
+        }
+
+        #[test]
+        fn tolerance_matches_naive(levels in reports()) {
+            prop_assert_eq!(
+                level_safety_tolerance(&levels) == 1,
+                naive_is_safe_with_tolerance(&levels)
+            );
+        }
+    }
+}
@@ -0,0 +1,335 @@
+use crate::error::{Checked, Overflow, Result};
+use crate::parse;
+use crate::solution::{Decision, Part, Solution};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input<'a> = Vec<&'a [u8]>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(contents: &str) -> Result<Self::Input<'_>> {
+        parse::each_line(contents.as_bytes(), Ok)
+    }
+
+    fn part1(lines: &Self::Input<'_>) -> Checked<i64> {
+        lines.iter().try_fold(0, |acc, line| eval_line(acc, line))
+    }
+
+    fn part2(lines: &Self::Input<'_>) -> Checked<i64> {
+        let (ans, _) = lines
+            .iter()
+            .try_fold((0, Enabler::default()), |(acc, fsm), line| {
+                eval_line_with_enable(acc, line, fsm)
+            })?;
+        Ok(ans)
+    }
+
+    fn explain(lines: &Self::Input<'_>, part: Part) -> Option<Vec<Decision>> {
+        Some(explain_instructions(lines, part == Part::Two))
+    }
+}
+
+/// Every complete `mul` instruction and, when `with_enabler`, every `do()` and
+/// `don't()`, in the order they appear. This runs the same state machines as
+/// the parts, remembering where each instruction started.
+fn explain_instructions(lines: &[&[u8]], with_enabler: bool) -> Vec<Decision> {
+    let mut decisions = Vec::new();
+    let mut enabled = true;
+    for (i, line) in lines.iter().enumerate() {
+        let mut mul = MulStateMachine::None;
+        let mut fsm = EnablerFSM::None;
+        let (mut mul_start, mut fsm_start) = (0, 0);
+        for (j, &c) in line.iter().enumerate() {
+            mul = mul.step(c);
+            fsm = fsm.step(c);
+            if let MulStateMachine::M = mul {
+                mul_start = j;
+            }
+            if let EnablerFSM::D = fsm {
+                fsm_start = j;
+            }
+            let at = |start: usize| {
+                let text = String::from_utf8_lossy(&line[start..=j]);
+                let column = String::from_utf8_lossy(&line[..start]).chars().count() + 1;
+                format!("{text} at line {}, column {column}", i + 1)
+            };
+
+            if let (true, Some(enables)) = (with_enabler, fsm.state()) {
+                let verdict = match (enables, enabled) {
+                    (true, true) => "enables mul, already enabled",
+                    (true, false) => "enables mul",
+                    (false, true) => "disables mul",
+                    (false, false) => "disables mul, already disabled",
+                };
+                decisions.push(Decision::new(at(fsm_start), verdict, 0));
+                enabled = enables;
+            }
+            if let MulStateMachine::Finished(x, y) = mul {
+                let decision = if enabled {
+                    Decision::new(at(mul_start), format!("adds {x} * {y}"), (x * y) as u64)
+                } else {
+                    Decision::new(at(mul_start), "skipped, mul is disabled", 0)
+                };
+                decisions.push(decision);
+            }
+        }
+    }
+    decisions
+}
+
+/// `acc` plus the products of the `mul` instructions in `line`.
+fn eval_line(mut acc: i64, line: &[u8]) -> Checked<i64> {
+    let mut state = MulStateMachine::None;
+    for &c in line {
+        state = state.step(c);
+        acc = add_product(acc, state.value())?;
+    }
+    Ok(acc)
+}
+
+fn eval_line_with_enable(mut acc: i64, line: &[u8], state: Enabler) -> Checked<(i64, Enabler)> {
+    let mut state = state;
+    for &c in line {
+        state = state.step(c);
+        acc = add_product(acc, state.value())?;
+    }
+    Ok((acc, state))
+}
+
+fn add_product(acc: i64, product: i64) -> Checked<i64> {
+    acc.checked_add(product).ok_or_else(|| {
+        Overflow::new(format!(
+            "adding the product {product} of a mul instruction to {acc} overflows i64"
+        ))
+    })
+}
+
+#[derive(Debug)]
+enum MulStateMachine {
+    None,
+    M,
+    U,
+    L,
+    Open,
+    FirstNum(i64),
+    SecondNum(i64, i64),
+    Finished(i64, i64),
+}
+
+impl MulStateMachine {
+    pub fn value(&self) -> i64 {
+        if let MulStateMachine::Finished(a, b) = self {
+            return a * b;
+        }
+        0
+    }
+    // stepped once per byte of input, so worth inlining into the loops
+    #[inline]
+    pub fn step(self, c: u8) -> Self {
+        match self {
+            MulStateMachine::None => {}
+            MulStateMachine::M => {
+                if c == b'u' {
+                    return MulStateMachine::U;
+                }
+            }
+            MulStateMachine::U => {
+                if c == b'l' {
+                    return MulStateMachine::L;
+                }
+            }
+            MulStateMachine::L => {
+                if c == b'(' {
+                    return MulStateMachine::Open;
+                }
+            }
+            MulStateMachine::Open => {
+                if c.is_ascii_digit() {
+                    return MulStateMachine::FirstNum((c - b'0') as i64);
+                }
+            }
+            MulStateMachine::FirstNum(x) => {
+                if x < 1000 {
+                    if c.is_ascii_digit() {
+                        return MulStateMachine::FirstNum(10 * x + (c - b'0') as i64);
+                    }
+                    if c == b',' {
+                        return MulStateMachine::SecondNum(x, 0);
+                    }
+                }
+            }
+            MulStateMachine::SecondNum(x, y) => {
+                if y < 1000 {
+                    if c.is_ascii_digit() {
+                        return MulStateMachine::SecondNum(x, 10 * y + (c - b'0') as i64);
+                    }
+                    if c == b')' {
+                        return MulStateMachine::Finished(x, y);
+                    }
+                }
+            }
+            MulStateMachine::Finished(_, _) => {}
+        } // no valid state transition, fall back to starting
+        if c == b'm' {
+            return MulStateMachine::M;
+        }
+        MulStateMachine::None
+    }
+}
+
+#[derive(Debug)]
+enum EnablerFSM {
+    None,
+    D,
+    O,
+    DoOpen,
+    DoClose,
+    N,
+    Apostrophe,
+    T,
+    DontOpen,
+    DontClose,
+}
+
+#[derive(Debug)]
+enum Enabler {
+    Do(EnablerFSM, MulStateMachine),
+    Dont(EnablerFSM),
+}
+
+impl EnablerFSM {
+    pub fn state(&self) -> Option<bool> {
+        match self {
+            EnablerFSM::DoClose => Some(true),
+            EnablerFSM::DontClose => Some(false),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn step(self, c: u8) -> Self {
+        match self {
+            EnablerFSM::None => {}
+            EnablerFSM::D => {
+                if c == b'o' {
+                    return EnablerFSM::O;
+                }
+            }
+            EnablerFSM::O => {
+                if c == b'n' {
+                    return EnablerFSM::N;
+                }
+                if c == b'(' {
+                    return EnablerFSM::DoOpen;
+                }
+            }
+            EnablerFSM::DoOpen => {
+                if c == b')' {
+                    return EnablerFSM::DoClose;
+                }
+            }
+            EnablerFSM::N => {
+                if c == b'\'' {
+                    return EnablerFSM::Apostrophe;
+                }
+            }
+            EnablerFSM::Apostrophe => {
+                if c == b't' {
+                    return EnablerFSM::T;
+                }
+            }
+            EnablerFSM::T => {
+                if c == b'(' {
+                    return EnablerFSM::DontOpen;
+                }
+            }
+            EnablerFSM::DontOpen => {
+                if c == b')' {
+                    return EnablerFSM::DontClose;
+                }
+            }
+            EnablerFSM::DoClose | EnablerFSM::DontClose => {}
+        }
+        if c == b'd' {
+            return EnablerFSM::D;
+        }
+        EnablerFSM::None
+    }
+}
+
+impl Enabler {
+    pub fn default() -> Self {
+        Enabler::Do(EnablerFSM::None, MulStateMachine::None)
+    }
+    pub fn value(&self) -> i64 {
+        match self {
+            Enabler::Do(_, mul) => mul.value(),
+            Enabler::Dont(_) => 0,
+        }
+    }
+
+    #[inline]
+    pub fn step(self, c: u8) -> Self {
+        match self {
+            Enabler::Do(fsm, mul) => {
+                let fsm = fsm.step(c);
+                if let Some(false) = fsm.state() {
+                    return Enabler::Dont(fsm);
+                }
+                Enabler::Do(fsm, mul.step(c))
+            }
+            Enabler::Dont(fsm) => {
+                let fsm = fsm.step(c);
+                if let Some(true) = fsm.state() {
+                    let mul = MulStateMachine::None;
+                    return Enabler::Do(fsm, mul.step(c));
+                }
+                Enabler::Dont(fsm)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../inputs/examples/day03.txt");
+    const EXAMPLE_PART2: &str = include_str!("../../inputs/examples/day03-part2.txt");
+
+    #[test]
+    fn example_part1() {
+        let input = Day03::parse(EXAMPLE).unwrap();
+        assert_eq!(Day03::part1(&input), Ok(161));
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day03::parse(EXAMPLE_PART2).unwrap();
+        assert_eq!(Day03::part2(&input), Ok(48));
+    }
+
+    #[test]
+    fn example_explained() {
+        let input = Day03::parse(EXAMPLE_PART2).unwrap();
+        let decisions = Day03::explain(&input, Part::Two).unwrap();
+        let lines: Vec<String> = decisions
+            .iter()
+            .map(|d| format!("{}: {} (+{})", d.item, d.verdict, d.contribution))
+            .collect();
+        assert_eq!(
+            lines,
+            [
+                "mul(2,4) at line 1, column 2: adds 2 * 4 (+8)",
+                "don't() at line 1, column 21: disables mul (+0)",
+                "mul(5,5) at line 1, column 29: skipped, mul is disabled (+0)",
+                "mul(11,8) at line 1, column 49: skipped, mul is disabled (+0)",
+                "do() at line 1, column 60: enables mul (+0)",
+                "mul(8,5) at line 1, column 65: adds 8 * 5 (+40)",
+            ]
+        );
+    }
+}
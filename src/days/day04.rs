@@ -0,0 +1,220 @@
+use crate::error::{Checked, Overflow, Result};
+use crate::geometry::{Direction, Point};
+use crate::grid::Grid;
+use crate::render::{Mark, Palette, Picture};
+use crate::solution::Solution;
+use std::collections::HashSet;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input<'a> = Grid<u8>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(contents: &str) -> Result<Self::Input<'_>> {
+        Grid::from_bytes(contents.as_bytes())
+    }
+
+    fn part1(grid: &Self::Input<'_>) -> Checked<u32> {
+        // every row, column and diagonal is checked forwards and backwards
+        // by is_xmas, which covers the remaining four directions
+        let rows = grid.rows().map(|row| count_xmas(row.iter()));
+        let columns = grid.columns().map(count_xmas);
+        let diagonals = grid.diagonals().map(count_xmas);
+        let anti_diagonals = grid.anti_diagonals().map(count_xmas);
+
+        rows.chain(columns)
+            .chain(diagonals)
+            .chain(anti_diagonals)
+            .try_fold(0, |acc: u32, count| {
+                acc.checked_add(count).ok_or_else(|| {
+                    Overflow::new(format!("adding {count} more XMAS to {acc} overflows u32"))
+                })
+            })
+    }
+
+    fn part2(grid: &Self::Input<'_>) -> Checked<u32> {
+        let count = x_mas_centres(grid).count();
+        u32::try_from(count)
+            .map_err(|_| Overflow::new(format!("{count} X-MAS are more than u32::MAX")))
+    }
+}
+
+/// The 'A' in the middle of every X-MAS.
+fn x_mas_centres(grid: &Grid<u8>) -> impl Iterator<Item = (usize, usize)> + '_ {
+    grid.positions().filter(|&pos| {
+        if grid[pos] != b'A' {
+            return false;
+        }
+        let corners: Option<Vec<u8>> = Direction::DIAGONAL
+            .iter()
+            .map(|&d| grid.step(pos, d).map(|p| grid[p]))
+            .collect();
+        let Some(&[up_right, down_right, down_left, up_left]) = corners.as_deref() else {
+            return false;
+        };
+        // each diagonal has to read MAS one way or the other; summing the
+        // corners, as this used to, also took M, M, X and N for an X-MAS
+        let is_mas = |a, b| matches!((a, b), (b'M', b'S') | (b'S', b'M'));
+        is_mas(up_left, down_right) && is_mas(up_right, down_left)
+    })
+}
+
+/// Every cell that is part of an XMAS, found by spelling the word from each
+/// cell in every direction.
+fn xmas_cells(grid: &Grid<u8>) -> HashSet<Point> {
+    let mut cells = HashSet::new();
+    for pos in grid.positions() {
+        for d in Direction::ALL {
+            let word: Vec<Point> = (0..4).map(|k| Point::from(pos) + d.offset() * k).collect();
+            let spelled = word
+                .iter()
+                .zip(b"XMAS")
+                .all(|(&p, c)| grid.get_point(p) == Some(c));
+            if spelled {
+                cells.extend(word);
+            }
+        }
+    }
+    cells
+}
+
+/// The letters, with every XMAS filled in and every X-MAS outlined.
+pub fn render(contents: &str, palette: &Palette) -> Result<Picture> {
+    let grid = Grid::from_bytes(contents.as_bytes())?;
+    let mut picture = Picture::from_grid(&grid, |_| palette.background);
+    picture.overlay(xmas_cells(&grid), palette.accent(0), Mark::Fill);
+    let x_mas = x_mas_centres(&grid).flat_map(|pos| {
+        let centre = Point::from(pos);
+        let corners = Direction::DIAGONAL.map(|d| d.forward(centre));
+        std::iter::once(centre).chain(corners)
+    });
+    picture.overlay(x_mas, palette.accent(1), Mark::Outline);
+    Ok(picture)
+}
+
+fn count_xmas<'a>(line: impl Iterator<Item = &'a u8>) -> u32 {
+    // the last four letters read, oldest first, so the line is scanned in
+    // place rather than collected
+    let mut window = [0; 4];
+    let mut count = 0;
+    for &c in line {
+        window = [window[1], window[2], window[3], c];
+        count += u32::from(is_xmas(window[0], window[1], window[2], window[3]));
+    }
+    count
+}
+
+/// Compares the letters themselves; summing their weighted codes, as this
+/// used to, also matched other words such as SANN, which sums like SAMX.
+fn is_xmas(a: u8, b: u8, c: u8, d: u8) -> bool {
+    matches!(
+        (a, b, c, d),
+        (b'X', b'M', b'A', b'S') | (b'S', b'A', b'M', b'X')
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../../inputs/examples/day04.txt");
+
+    #[test]
+    fn example_part1() {
+        let input = Day04::parse(EXAMPLE).unwrap();
+        assert_eq!(Day04::part1(&input), Ok(18));
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day04::parse(EXAMPLE).unwrap();
+        assert_eq!(Day04::part2(&input), Ok(9));
+    }
+
+    /// Reference answer for part 1: spell the word out from every cell in
+    /// every direction.
+    fn naive_xmas(grid: &Grid<u8>) -> u32 {
+        let mut count = 0;
+        for pos in grid.positions() {
+            for d in Direction::ALL {
+                let found = b"XMAS".iter().enumerate().all(|(k, c)| {
+                    let p = Point::from(pos) + d.offset() * k as i32;
+                    grid.get_point(p) == Some(c)
+                });
+                count += u32::from(found);
+            }
+        }
+        count
+    }
+
+    /// Reference answer for part 2: compare the corners and centre of every
+    /// 3x3 window, read left to right and top to bottom, against the four
+    /// ways an X-MAS can be written.
+    fn naive_x_mas(grid: &Grid<u8>) -> u32 {
+        const X_MAS: [&[u8]; 4] = [b"MMASS", b"MSAMS", b"SMASM", b"SSAMM"];
+        let mut count = 0;
+        for i in 0..grid.n().saturating_sub(2) {
+            for j in 0..grid.m().saturating_sub(2) {
+                let window = [
+                    (i, j),
+                    (i, j + 2),
+                    (i + 1, j + 1),
+                    (i + 2, j),
+                    (i + 2, j + 2),
+                ];
+                let letters = window.map(|pos| grid[pos]);
+                count += u32::from(X_MAS.contains(&&letters[..]));
+            }
+        }
+        count
+    }
+
+    /// Grids over a small alphabet so the words show up often. Letters other
+    /// than X, M, A and S check the solver only counts the words themselves.
+    fn grids(alphabet: &'static [char]) -> impl Strategy<Value = Grid<u8>> {
+        (1..12usize, 1..12usize)
+            .prop_flat_map(move |(n, m)| {
+                prop::collection::vec(prop::sample::select(alphabet), n * m)
+                    .prop_map(move |cells| (n, m, cells))
+            })
+            .prop_map(|(n, m, cells)| {
+                let text: String = cells
+                    .chunks(m)
+                    .map(|row| row.iter().collect::<String>() + "\n")
+                    .collect();
+                assert_eq!(text.lines().count(), n);
+                Grid::from_bytes(text.as_bytes()).unwrap()
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn part1_matches_naive(grid in grids(&['X', 'M', 'A', 'S'])) {
+            prop_assert_eq!(Day04::part1(&grid), Ok(naive_xmas(&grid)));
+        }
+
+        #[test]
+        fn part2_matches_naive(grid in grids(&['X', 'M', 'A', 'S'])) {
+            prop_assert_eq!(Day04::part2(&grid), Ok(naive_x_mas(&grid)));
+        }
+
+        #[test]
+        fn part1_matches_naive_with_other_letters(
+            grid in grids(&['X', 'M', 'A', 'S', 'L', 'N', 'Q', 'Z'])
+        ) {
+            prop_assert_eq!(Day04::part1(&grid), Ok(naive_xmas(&grid)));
+        }
+
+        #[test]
+        fn part2_matches_naive_with_other_letters(
+            grid in grids(&['X', 'M', 'A', 'S', 'L', 'N', 'Q', 'Z'])
+        ) {
+            prop_assert_eq!(Day04::part2(&grid), Ok(naive_x_mas(&grid)));
+        }
+    }
+}
@@ -0,0 +1,227 @@
+use crate::error::{Checked, Overflow, ParseError, Result};
+use crate::parse;
+use crate::solution::{Decision, Part, Solution};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+pub struct Day05;
+
+pub struct Manual {
+    two_way: TwoWayOrdering,
+    updates: Vec<Vec<u32>>,
+}
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input<'a> = Manual;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(contents: &str) -> Result<Self::Input<'_>> {
+        let mut iter = parse::lines(parse::trim_blank_lines(contents.as_bytes())).enumerate();
+
+        let mut two_way = TwoWayOrdering::default();
+        let mut found_separator = false;
+        for (i, s) in iter.by_ref() {
+            if s.is_empty() {
+                found_separator = true;
+                break;
+            }
+            let (x, y) = parse::pair(s, b'|').map_err(|e| e.on_line(i + 1))?;
+            two_way.add_pair(x, y);
+        }
+        if !found_separator {
+            return Err(ParseError::at_end(
+                contents,
+                "expected a blank line between the rules and the updates",
+            ));
+        }
+
+        let mut updates = Vec::new();
+        for (i, s) in iter {
+            let pages: Vec<u32> = parse::integers(s, b',').map_err(|e| e.on_line(i + 1))?;
+            if pages.is_empty() {
+                return Err(ParseError::in_line(s, s, "an update needs pages").on_line(i + 1));
+            }
+            updates.push(pages);
+        }
+        if updates.is_empty() {
+            return Err(ParseError::at_end(contents, "expected at least one update"));
+        }
+        Ok(Manual { two_way, updates })
+    }
+
+    fn part1(manual: &Self::Input<'_>) -> Checked<u32> {
+        let two_way = &manual.two_way;
+
+        let mut ans = 0;
+        for pages in &manual.updates {
+            'top: {
+                let mut seen = HashSet::new();
+
+                for x in pages {
+                    if two_way.ordering_wrong(*x, &seen) {
+                        break 'top;
+                    }
+                    seen.insert(*x);
+                }
+                ans = add_middle_page(ans, pages)?;
+            }
+        }
+        Ok(ans)
+    }
+
+    fn part2(manual: &Self::Input<'_>) -> Checked<u32> {
+        let two_way = &manual.two_way;
+
+        let mut ans = 0;
+        for pages in &manual.updates {
+            let mut seen = HashSet::new();
+
+            let mut should_sort = false;
+            for x in pages {
+                if two_way.ordering_wrong(*x, &seen) {
+                    should_sort = true;
+                    break;
+                }
+                seen.insert(*x);
+            }
+            if should_sort {
+                let mut pages = pages.clone();
+                pages.sort_by(|a, b| two_way.get_ord(a, b));
+                ans = add_middle_page(ans, &pages)?;
+            }
+        }
+        Ok(ans)
+    }
+
+    fn explain(manual: &Self::Input<'_>, part: Part) -> Option<Vec<Decision>> {
+        let two_way = &manual.two_way;
+        let decisions = manual.updates.iter().map(|pages| {
+            let update = join(pages);
+            let violation = two_way.first_violation(pages);
+            match (part, violation) {
+                (Part::One, None) => {
+                    let middle = pages[pages.len() / 2];
+                    Decision::new(
+                        update,
+                        format!("in order, middle page {middle}"),
+                        middle.into(),
+                    )
+                }
+                (Part::One, Some((x, y))) => {
+                    Decision::new(update, format!("out of order, {x} must come before {y}"), 0)
+                }
+                (Part::Two, None) => Decision::new(update, "already in order", 0),
+                (Part::Two, Some((x, y))) => {
+                    let mut pages = pages.clone();
+                    pages.sort_by(|a, b| two_way.get_ord(a, b));
+                    let middle = pages[pages.len() / 2];
+                    let verdict = format!(
+                        "{x} must come before {y}, reordered to {}, middle page {middle}",
+                        join(&pages)
+                    );
+                    Decision::new(update, verdict, middle.into())
+                }
+            }
+        });
+        Some(decisions.collect())
+    }
+}
+
+fn join(pages: &[u32]) -> String {
+    let pages: Vec<String> = pages.iter().map(|page| page.to_string()).collect();
+    pages.join(",")
+}
+
+fn add_middle_page(sum: u32, pages: &[u32]) -> Checked<u32> {
+    let middle = pages[pages.len() / 2];
+    sum.checked_add(middle).ok_or_else(|| {
+        Overflow::new(format!(
+            "adding middle page {middle} to the sum {sum} overflows u32"
+        ))
+    })
+}
+
+#[derive(Default)]
+struct TwoWayOrdering {
+    less: HashMap<u32, HashSet<u32>>,
+}
+
+impl TwoWayOrdering {
+    pub fn add_pair(&mut self, a: u32, b: u32) {
+        self.less.entry(a).or_default().insert(b);
+    }
+
+    pub fn ordering_wrong(&self, a: u32, seen: &HashSet<u32>) -> bool {
+        let Some(less) = self.less.get(&a) else {
+            return false;
+        };
+        less.intersection(seen).count() > 0
+    }
+
+    /// The first rule `pages` breaks, as the page that must come first and the
+    /// page printed before it.
+    pub fn first_violation(&self, pages: &[u32]) -> Option<(u32, u32)> {
+        let mut seen = HashSet::new();
+        for &x in pages {
+            if let Some(less) = self.less.get(&x) {
+                if let Some(&y) = pages.iter().find(|y| less.contains(y) && seen.contains(*y)) {
+                    return Some((x, y));
+                }
+            }
+            seen.insert(x);
+        }
+        None
+    }
+
+    pub fn get_ord(&self, a: &u32, b: &u32) -> Ordering {
+        if let Some(less) = self.less.get(a) {
+            if less.contains(b) {
+                return Ordering::Less;
+            }
+        }
+        if let Some(more) = self.less.get(b) {
+            if more.contains(a) {
+                return Ordering::Greater;
+            }
+        }
+        Ordering::Equal
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../inputs/examples/day05.txt");
+
+    #[test]
+    fn example_part1() {
+        let input = Day05::parse(EXAMPLE).unwrap();
+        assert_eq!(Day05::part1(&input), Ok(143));
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day05::parse(EXAMPLE).unwrap();
+        assert_eq!(Day05::part2(&input), Ok(123));
+    }
+
+    #[test]
+    fn example_explained() {
+        let input = Day05::parse(EXAMPLE).unwrap();
+        let part1 = Day05::explain(&input, Part::One).unwrap();
+        assert_eq!(part1[0].verdict, "in order, middle page 61");
+        assert_eq!(part1[3].verdict, "out of order, 97 must come before 75");
+        let part2 = Day05::explain(&input, Part::Two).unwrap();
+        assert_eq!(part2[0].verdict, "already in order");
+        assert_eq!(
+            part2[3].verdict,
+            "97 must come before 75, reordered to 97,75,47,61,53, middle page 47"
+        );
+        assert_eq!(part2[3].item, "75,97,47,61,53");
+    }
+}
@@ -0,0 +1,300 @@
+use crate::error::{Checked, Overflow, ParseError, Result};
+use crate::explore::Exploration;
+use crate::geometry::{Direction, Point};
+use crate::grid::Grid;
+use crate::record::Recorder;
+use crate::render::{Mark, Palette, Picture};
+use crate::solution::Solution;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input<'a> = (Guard, Grid<Tile>);
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(contents: &str) -> Result<Self::Input<'_>> {
+        parse_map(contents)
+    }
+
+    /// The positions the guard visits. A guard walking in a loop visits only
+    /// finitely many, so it is answered the same way rather than as an error.
+    fn part1((guard, grid): &Self::Input<'_>) -> Checked<u32> {
+        let visited = get_travelled_positions(*guard, grid).len();
+        u32::try_from(visited).map_err(|_| {
+            Overflow::new(format!(
+                "the guard visits {visited} positions, more than u32::MAX"
+            ))
+        })
+    }
+
+    fn part2((guard, grid): &Self::Input<'_>) -> Checked<u32> {
+        let mut grid = grid.clone();
+
+        // try every positions :)
+        let mut ans: u32 = 0;
+        for pos in grid.positions() {
+            if let Tile::Blocked = grid[pos] {
+                continue;
+            }
+            // the guard's starting position cannot be obstructed
+            if Point::from(pos) == guard.position() {
+                continue;
+            }
+
+            // block the position
+            grid[pos] = Tile::Blocked;
+            // check if we ever loop
+            if would_loop(*guard, &grid) {
+                ans = ans.checked_add(1).ok_or_else(|| {
+                    Overflow::new("more than u32::MAX obstructions make the guard loop")
+                })?;
+            }
+            // unblock
+            grid[pos] = Tile::Clear;
+        }
+        Ok(ans)
+    }
+}
+
+fn parse_map(text: &str) -> Result<(Guard, Grid<Tile>)> {
+    let mut guard = None;
+    let mut cell = 0;
+    let grid = Grid::parse(text.as_bytes(), |b| {
+        cell += 1;
+        match b {
+            b'#' => Some(Tile::Blocked),
+            b'.' => Some(Tile::Clear),
+            b'^' => {
+                guard.get_or_insert(cell - 1);
+                Some(Tile::Clear)
+            }
+            _ => None,
+        }
+    })?;
+    let Some(start) = guard else {
+        return Err(ParseError::at_end(text, "expected a guard '^' on the map"));
+    };
+    let guard = Guard::new(Point::from((start / grid.m(), start % grid.m())));
+    Ok((guard, grid))
+}
+
+fn get_travelled_positions(guard: Guard, grid: &Grid<Tile>) -> HashSet<Point> {
+    let mut positions = HashSet::from([guard.position()]);
+    patrol(guard, grid, |guard| {
+        positions.insert(guard.position());
+    });
+    positions
+}
+
+/// Walks `guard` until it leaves the lab, calling `on_step` after every move
+/// or turn. Stops and returns false if the guard walks in a loop, i.e. comes
+/// back to a position it already stood on facing the same way.
+fn patrol(mut guard: Guard, grid: &Grid<Tile>, mut on_step: impl FnMut(&Guard)) -> bool {
+    let mut visited: HashMap<Point, HashSet<Direction>> = HashMap::new();
+    visited
+        .entry(guard.position())
+        .or_default()
+        .insert(guard.direction);
+    while guard.step(grid) {
+        let first = visited
+            .entry(guard.position())
+            .or_default()
+            .insert(guard.direction);
+        if !first {
+            return false;
+        }
+        on_step(&guard);
+    }
+    true
+}
+
+fn would_loop(guard: Guard, grid: &Grid<Tile>) -> bool {
+    !patrol(guard, grid, |_| {})
+}
+
+/// The lab, with the cells the guard walks through dotted and its starting
+/// position filled in.
+pub fn render(contents: &str, palette: &Palette) -> Result<Picture> {
+    let (guard, grid) = parse_map(contents)?;
+    let mut picture = Picture::from_grid(&grid, |tile| match tile {
+        Tile::Blocked => palette.foreground,
+        Tile::Clear => palette.background,
+    });
+    let visited = get_travelled_positions(guard, &grid);
+    picture.overlay(visited, palette.accent(0), Mark::Dot);
+    picture.overlay([guard.position()], palette.accent(1), Mark::Fill);
+    Ok(picture)
+}
+
+/// Records the guard's patrol one step at a time, each frame showing the cells
+/// visited so far and where the guard stands.
+pub fn record(contents: &str, palette: &Palette, recorder: &mut Recorder) -> Result<()> {
+    let (guard, grid) = parse_map(contents)?;
+    let lab = Picture::from_grid(&grid, |tile| match tile {
+        Tile::Blocked => palette.foreground,
+        Tile::Clear => palette.background,
+    });
+    let draw = |visited: &[Point], guard: &Guard| {
+        let mut picture = lab.clone();
+        picture.overlay(visited.iter().copied(), palette.accent(0), Mark::Dot);
+        picture.overlay([guard.position()], palette.accent(1), Mark::Fill);
+        picture
+    };
+
+    // cells in the order the guard first reaches them, so the cells visited
+    // by any step are a prefix of the path
+    let mut path = vec![guard.position()];
+    let mut seen = HashSet::from([guard.position()]);
+    let mut last = guard;
+    recorder.step(|| draw(&path, &guard));
+    patrol(guard, &grid, |guard| {
+        if seen.insert(guard.position()) {
+            path.push(guard.position());
+        }
+        recorder.step(|| draw(&path, guard));
+        last = *guard;
+    });
+    recorder.finish(|| draw(&path, &last));
+    Ok(())
+}
+
+/// Explores the patrol one guard step at a time, with the cells visited so
+/// far dotted and the cells where the guard turned filled in.
+pub fn explore(contents: &str, palette: &Palette) -> Result<Exploration> {
+    let (guard, grid) = parse_map(contents)?;
+    let glyphs = grid.map(|tile| match tile {
+        Tile::Blocked => '#',
+        Tile::Clear => '.',
+    });
+    let mut exploration = Exploration::new(
+        "Day 6: Guard Gallivant",
+        glyphs,
+        palette.background,
+        |&c| match c {
+            '#' => palette.foreground,
+            _ => palette.accent(3),
+        },
+    );
+    let path = exploration.layer("path", Mark::Dot);
+    let turns = exploration.layer("turns", Mark::Fill);
+
+    let at = |guard: &Guard| Some((guard.position(), guard.glyph()));
+    let place = |p: Point| format!("row {}, column {}", p.row + 1, p.col + 1);
+    let mut visited = HashSet::from([guard.position()]);
+    exploration.step(
+        format!("the guard starts at {}", place(guard.position())),
+        at(&guard),
+    );
+    exploration.reveal(path, [guard.position()], palette.accent(0));
+
+    let mut before = guard;
+    let leaves = patrol(guard, &grid, |guard| {
+        visited.insert(guard.position());
+        let turned = guard.position() == before.position();
+        let note = if turned {
+            format!("blocked at {}, turns right", place(guard.position()))
+        } else {
+            format!("moves to {}", place(guard.position()))
+        };
+        exploration.step(
+            format!("{note}; {} cells visited", visited.len()),
+            at(guard),
+        );
+        exploration.reveal(path, [guard.position()], palette.accent(0));
+        if turned {
+            exploration.reveal(turns, [guard.position()], palette.accent(2));
+        }
+        before = *guard;
+    });
+    let end = if leaves {
+        "leaves the lab"
+    } else {
+        "walks in a loop"
+    };
+    exploration.step(
+        format!("the guard {end} after visiting {} cells", visited.len()),
+        None,
+    );
+    Ok(exploration)
+}
+
+#[derive(Debug, Clone)]
+pub enum Tile {
+    Blocked,
+    Clear,
+}
+
+#[derive(Default, Debug, Clone, Copy)]
+pub struct Guard {
+    position: Point,
+    direction: Direction,
+}
+
+impl Guard {
+    /// A guard at `position`, facing up.
+    pub fn new(position: Point) -> Self {
+        Self {
+            position,
+            direction: Direction::Up,
+        }
+    }
+
+    pub fn step(&mut self, grid: &Grid<Tile>) -> bool {
+        let next = self.direction.forward(self.position);
+
+        match grid.get_point(next) {
+            None => return false,
+            Some(Tile::Blocked) => self.direction = self.direction.clockwise(),
+            Some(Tile::Clear) => self.position = next,
+        }
+        true
+    }
+
+    pub fn position(&self) -> Point {
+        self.position
+    }
+
+    /// The guard drawn as an arrow pointing where it faces.
+    pub fn glyph(&self) -> char {
+        match self.direction {
+            Direction::Up => '^',
+            Direction::Right => '>',
+            Direction::Down => 'v',
+            _ => '<',
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../inputs/examples/day06.txt");
+
+    #[test]
+    fn example_part1() {
+        let input = Day06::parse(EXAMPLE).unwrap();
+        assert_eq!(Day06::part1(&input), Ok(41));
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day06::parse(EXAMPLE).unwrap();
+        assert_eq!(Day06::part2(&input), Ok(6));
+    }
+
+    #[test]
+    fn guards_walking_in_a_loop_still_solve() {
+        let input = Day06::parse(".#.\n#^#\n.#.\n").unwrap();
+        assert_eq!(Day06::part1(&input), Ok(1));
+        assert_eq!(Day06::part2(&input), Ok(4));
+
+        let input = Day06::parse(".#..\n...#\n#^..\n..#.\n").unwrap();
+        assert_eq!(Day06::part1(&input), Ok(4));
+    }
+}
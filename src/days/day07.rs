@@ -0,0 +1,263 @@
+use crate::error::{Checked, Overflow, ParseError, Result};
+use crate::parse;
+use crate::solution::{Decision, Part, Solution};
+use std::fmt;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input<'a> = Vec<Calibration>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(contents: &str) -> Result<Self::Input<'_>> {
+        parse::each_line(contents.as_bytes(), Calibration::from)
+    }
+
+    fn part1(calibrations: &Self::Input<'_>) -> Checked<u64> {
+        sum_test_values(calibrations.iter().filter(|c| c.can_calibrate()))
+    }
+
+    fn part2(calibrations: &Self::Input<'_>) -> Checked<u64> {
+        sum_test_values(calibrations.iter().filter(|c| c.can_calibrate_p2()))
+    }
+
+    fn explain(calibrations: &Self::Input<'_>, part: Part) -> Option<Vec<Decision>> {
+        let concat = part == Part::Two;
+        let decisions =
+            calibrations
+                .iter()
+                .map(|calibration| match calibration.operators(concat) {
+                    Some(operators) => {
+                        let mut equation = calibration.equation[0].to_string();
+                        for (op, x) in operators.iter().zip(&calibration.equation[1..]) {
+                            equation += &format!(" {op} {x}");
+                        }
+                        let verdict = format!("true as {equation}");
+                        Decision::new(calibration, verdict, calibration.test_value)
+                    }
+                    None if concat => Decision::new(calibration, "no mix of +, * and || works", 0),
+                    None => Decision::new(calibration, "no mix of + and * works", 0),
+                });
+        Some(decisions.collect())
+    }
+}
+
+fn sum_test_values<'a>(mut calibrations: impl Iterator<Item = &'a Calibration>) -> Checked<u64> {
+    calibrations.try_fold(0, |acc: u64, calibration| {
+        acc.checked_add(calibration.test_value).ok_or_else(|| {
+            Overflow::new(format!(
+                "adding the test value of \"{calibration}\" to {acc} overflows u64"
+            ))
+        })
+    })
+}
+
+// Every intermediate value below is checked: one that overflows u64 is also
+// bigger than any test value, so that branch is dropped just like one that
+// merely overshoots.
+
+#[derive(Debug)]
+pub struct Calibration {
+    test_value: u64,
+    equation: Vec<u64>,
+}
+
+impl Calibration {
+    pub fn from(line: &[u8]) -> Result<Self> {
+        let Some((v, list)) = parse::split_once(line, b':') else {
+            return Err(ParseError::in_line(
+                line,
+                line,
+                "unable to find test value in line",
+            ));
+        };
+        let test_value = parse::integer(line, v)?;
+
+        let equation: Vec<u64> = parse::integers(list, b' ').map_err(|e| e.within(line, list))?;
+        if equation.is_empty() {
+            return Err(ParseError::in_line(line, list, "equation has no numbers"));
+        }
+        Ok(Self {
+            test_value,
+            equation,
+        })
+    }
+
+    fn can_calibrate_recursive(&self, acc: u64, eq: &[u64]) -> bool {
+        if acc > self.test_value {
+            return false;
+        }
+        if eq.is_empty() {
+            return self.test_value == acc;
+        }
+        let try_next =
+            |acc: Option<u64>| acc.is_some_and(|acc| self.can_calibrate_recursive(acc, &eq[1..]));
+        try_next(acc.checked_mul(eq[0])) || try_next(acc.checked_add(eq[0]))
+    }
+
+    pub fn can_calibrate(&self) -> bool {
+        self.can_calibrate_recursive(self.equation[0], &self.equation[1..])
+    }
+
+    /// The digits of `x` followed by those of `y`, unless that overflows.
+    pub fn concat(x: u64, y: u64) -> Option<u64> {
+        let mut pow_10: u64 = 1;
+        while pow_10 <= y {
+            pow_10 = pow_10.checked_mul(10)?;
+        }
+        x.checked_mul(pow_10)?.checked_add(y)
+    }
+
+    fn can_calibrate_recursive_p2(&self, acc: u64, eq: &[u64]) -> bool {
+        if acc > self.test_value {
+            // should not explore this execution path
+            return false;
+        }
+        if eq.is_empty() {
+            return self.test_value == acc;
+        }
+        let try_next = |acc: Option<u64>| {
+            acc.is_some_and(|acc| self.can_calibrate_recursive_p2(acc, &eq[1..]))
+        };
+        try_next(Self::concat(acc, eq[0]))
+            || try_next(acc.checked_mul(eq[0]))
+            || try_next(acc.checked_add(eq[0]))
+    }
+
+    pub fn can_calibrate_p2(&self) -> bool {
+        self.can_calibrate_recursive_p2(self.equation[0], &self.equation[1..])
+    }
+
+    /// The operators, evaluated left to right, that make the equation true,
+    /// with `||` allowed when `concat` is.
+    pub fn operators(&self, concat: bool) -> Option<Vec<&'static str>> {
+        let mut operators = Vec::with_capacity(self.equation.len() - 1);
+        self.find_operators(
+            self.equation[0],
+            &self.equation[1..],
+            concat,
+            &mut operators,
+        )
+        .then_some(operators)
+    }
+
+    fn find_operators(
+        &self,
+        acc: u64,
+        eq: &[u64],
+        concat: bool,
+        operators: &mut Vec<&'static str>,
+    ) -> bool {
+        if acc > self.test_value {
+            return false;
+        }
+        if eq.is_empty() {
+            return self.test_value == acc;
+        }
+        let candidates = [
+            ("+", acc.checked_add(eq[0])),
+            ("*", acc.checked_mul(eq[0])),
+            ("||", Self::concat(acc, eq[0]).filter(|_| concat)),
+        ];
+        for (op, next) in candidates {
+            let Some(next) = next else {
+                continue;
+            };
+            operators.push(op);
+            if self.find_operators(next, &eq[1..], concat, operators) {
+                return true;
+            }
+            operators.pop();
+        }
+        false
+    }
+}
+
+impl fmt::Display for Calibration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:", self.test_value)?;
+        for x in &self.equation {
+            write!(f, " {}", x)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../inputs/examples/day07.txt");
+
+    #[test]
+    fn parse_errors_point_into_the_line() {
+        let e = Calibration::from(b"190: 10 x9").unwrap_err();
+        assert_eq!((e.column, e.text.as_str()), (9, "x9"));
+
+        let e = Calibration::from(b"190 10 19").unwrap_err();
+        assert_eq!(e.column, 1);
+    }
+
+    #[test]
+    fn concat_tests() {
+        assert_eq!(Some(156), Calibration::concat(15, 6));
+        assert_eq!(Some(156), Calibration::concat(1, 56));
+        assert_eq!(Some(12345), Calibration::concat(12, 345));
+        assert_eq!(
+            Some(12345),
+            Calibration::concat(4, 5)
+                .and_then(|x| Calibration::concat(3, x))
+                .and_then(|x| Calibration::concat(2, x))
+                .and_then(|x| Calibration::concat(1, x))
+        );
+        assert_eq!(Some(11), Calibration::concat(1, 1));
+        assert_eq!(Some(110), Calibration::concat(1, 10));
+        assert_eq!(Some(1010), Calibration::concat(10, 10));
+        assert_eq!(None, Calibration::concat(2, u64::MAX / 10));
+        assert_eq!(None, Calibration::concat(1, u64::MAX));
+    }
+
+    #[test]
+    fn huge_equations_are_not_calibrated() {
+        let input = Day07::parse("18446744073709551615: 4294967296 4294967296 1\n").unwrap();
+        assert_eq!(Day07::part1(&input), Ok(0));
+        assert_eq!(Day07::part2(&input), Ok(0));
+    }
+
+    #[test]
+    fn overflowing_sum_is_an_error() {
+        let line = "18446744073709551615: 18446744073709551615\n";
+        let input = Day07::parse(&line.repeat(2)).unwrap();
+        let e = Day07::part1(&input).unwrap_err();
+        assert!(e.context.contains("18446744073709551615"), "{e}");
+    }
+
+    #[test]
+    fn example_part1() {
+        let input = Day07::parse(EXAMPLE).unwrap();
+        assert_eq!(Day07::part1(&input), Ok(3749));
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day07::parse(EXAMPLE).unwrap();
+        assert_eq!(Day07::part2(&input), Ok(11387));
+    }
+
+    #[test]
+    fn example_explained() {
+        let input = Day07::parse(EXAMPLE).unwrap();
+        let part1 = Day07::explain(&input, Part::One).unwrap();
+        assert_eq!(part1[0].item, "190: 10 19");
+        assert_eq!(part1[0].verdict, "true as 10 * 19");
+        assert_eq!(part1[1].verdict, "true as 81 + 40 * 27");
+        assert_eq!(part1[3].verdict, "no mix of + and * works");
+        assert_eq!(part1[3].contribution, 0);
+        let part2 = Day07::explain(&input, Part::Two).unwrap();
+        assert_eq!(part2[3].verdict, "true as 15 || 6");
+        assert_eq!(part2[3].contribution, 156);
+    }
+}
@@ -0,0 +1,175 @@
+use crate::error::{Checked, Overflow, Result};
+use crate::geometry::Point;
+use crate::grid::Grid;
+use crate::render::{Mark, Palette, Picture};
+use crate::solution::Solution;
+use std::collections::{HashMap, HashSet};
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input<'a> = FrequencyMap;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(contents: &str) -> Result<Self::Input<'_>> {
+        Ok(FrequencyMap::from(&Grid::from_bytes(contents.as_bytes())?))
+    }
+
+    fn part1(mapping: &Self::Input<'_>) -> Checked<u32> {
+        mapping.get_antinode_count()
+    }
+
+    fn part2(mapping: &Self::Input<'_>) -> Checked<u32> {
+        mapping.get_resonant_antinode_count()
+    }
+}
+
+pub struct FrequencyMap {
+    freq: HashMap<u8, Vec<Point>>,
+    n: usize,
+    m: usize,
+}
+
+impl FrequencyMap {
+    pub fn from(grid: &Grid<u8>) -> Self {
+        let mut freq: HashMap<u8, Vec<Point>> = HashMap::new();
+
+        for pos in grid.positions() {
+            if grid[pos] == b'.' {
+                continue;
+            }
+            freq.entry(grid[pos]).or_default().push(Point::from(pos));
+        }
+
+        Self {
+            freq,
+            n: grid.n(),
+            m: grid.m(),
+        }
+    }
+    pub fn in_bounds(&self, loc: Point) -> bool {
+        loc.to_index()
+            .is_some_and(|(i, j)| i < self.n && j < self.m)
+    }
+
+    pub fn get_antinode_locs(a: Point, b: Point) -> (Point, Point) {
+        (b * 2 - a, a * 2 - b)
+    }
+
+    /// Every antinode on the map, whichever antennas it comes from.
+    pub fn get_antinodes(&self) -> HashSet<Point> {
+        let mut seen: HashSet<_> = HashSet::new();
+        for list in self.freq.values() {
+            let l = list.len();
+            for i in 0..l {
+                for j in (i + 1)..l {
+                    let (l1, l2) = Self::get_antinode_locs(list[i], list[j]);
+                    seen.extend([l1, l2].into_iter().filter(|&loc| self.in_bounds(loc)));
+                }
+            }
+        }
+        seen
+    }
+
+    pub fn get_antinode_count(&self) -> Checked<u32> {
+        count_antinodes(self.get_antinodes())
+    }
+
+    pub fn get_resonant_antinode_locs(&self, a: Point, b: Point) -> Vec<Point> {
+        let d = b - a;
+        let mut locs: Vec<Point> = Vec::new();
+
+        let mut adding = a + d;
+        while self.in_bounds(adding) {
+            locs.push(adding);
+            adding += d;
+        }
+        // need to include current node position as well
+        let mut subtracting = a;
+        while self.in_bounds(subtracting) {
+            locs.push(subtracting);
+            subtracting -= d;
+        }
+
+        locs
+    }
+
+    /// Every antinode on the map once resonant harmonics are accounted for.
+    pub fn get_resonant_antinodes(&self) -> HashSet<Point> {
+        let mut seen: HashSet<_> = HashSet::new();
+        for list in self.freq.values() {
+            let l = list.len();
+            for i in 0..l {
+                for j in (i + 1)..l {
+                    seen.extend(self.get_resonant_antinode_locs(list[i], list[j]));
+                }
+            }
+        }
+        seen
+    }
+
+    pub fn get_resonant_antinode_count(&self) -> Checked<u32> {
+        count_antinodes(self.get_resonant_antinodes())
+    }
+}
+
+fn count_antinodes(antinodes: HashSet<Point>) -> Checked<u32> {
+    let count = antinodes.len();
+    u32::try_from(count)
+        .map_err(|_| Overflow::new(format!("{count} antinodes are more than u32::MAX")))
+}
+
+/// The antennas coloured by frequency, with resonant antinodes dotted and the
+/// antinodes of part 1 outlined.
+pub fn render(contents: &str, palette: &Palette) -> Result<Picture> {
+    let grid = Grid::from_bytes(contents.as_bytes())?;
+    let mapping = FrequencyMap::from(&grid);
+    let mut frequencies: Vec<u8> = mapping.freq.keys().copied().collect();
+    frequencies.sort_unstable();
+
+    let mut picture = Picture::from_grid(&grid, |&c| match frequencies.binary_search(&c) {
+        Ok(k) => palette.accent(k + 2),
+        Err(_) => palette.background,
+    });
+    picture.overlay(
+        mapping.get_resonant_antinodes(),
+        palette.accent(1),
+        Mark::Dot,
+    );
+    picture.overlay(mapping.get_antinodes(), palette.accent(0), Mark::Outline);
+    Ok(picture)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../inputs/examples/day08.txt");
+
+    #[test]
+    fn test_anti_locs() {
+        let a = Point::new(4, 6);
+        let b = Point::new(5, 4);
+        let anti1 = Point::new(6, 2);
+        let anti2 = Point::new(3, 8);
+
+        let (l1, l2) = FrequencyMap::get_antinode_locs(a, b);
+
+        assert!((l1 == anti1 && l2 == anti2) || (l2 == anti1 && l1 == anti2));
+    }
+
+    #[test]
+    fn example_part1() {
+        let input = Day08::parse(EXAMPLE).unwrap();
+        assert_eq!(Day08::part1(&input), Ok(14));
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day08::parse(EXAMPLE).unwrap();
+        assert_eq!(Day08::part2(&input), Ok(34));
+    }
+}
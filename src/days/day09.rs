@@ -0,0 +1,365 @@
+use crate::error::{Checked, Overflow, ParseError, Result};
+use crate::parse;
+use crate::record::Recorder;
+use crate::render::{Palette, Picture};
+use crate::solution::{Part, Solution};
+use core::fmt;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input<'a> = Vec<DiskMap>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(contents: &str) -> Result<Self::Input<'_>> {
+        parse::each_line(contents.as_bytes(), DiskMap::from)
+    }
+
+    fn part1(disks: &Self::Input<'_>) -> Checked<u64> {
+        sum_checksums(disks.iter().map(|disk| disk.compress()))
+    }
+
+    fn part2(disks: &Self::Input<'_>) -> Checked<u64> {
+        sum_checksums(disks.iter().map(|disk| disk.compress_no_fragmentation()))
+    }
+}
+
+fn sum_checksums(disks: impl Iterator<Item = DiskMap>) -> Checked<u64> {
+    disks.enumerate().try_fold(0, |acc: u64, (i, disk)| {
+        acc.checked_add(disk.checksum()?).ok_or_else(|| {
+            Overflow::new(format!(
+                "adding the checksum of disk {} to {acc} overflows u64",
+                i + 1
+            ))
+        })
+    })
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct FileBlock {
+    // if None this block is free
+    id: Option<u32>,
+    size: u32,
+}
+
+impl FileBlock {
+    pub fn expand(&self) -> Vec<Self> {
+        let mut res = Vec::with_capacity(self.size as usize);
+        for _ in 0..self.size {
+            res.push(FileBlock {
+                id: self.id,
+                size: 1,
+            });
+        }
+        res
+    }
+
+    pub fn is_free(&self) -> bool {
+        self.id.is_none()
+    }
+}
+
+pub struct DiskMap {
+    files: Vec<FileBlock>,
+}
+
+/// A file, or a block of one, moved during compaction: `size` blocks of file
+/// `id` from block `from` to block `to`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    pub id: u32,
+    pub from: usize,
+    pub to: usize,
+    pub size: u32,
+}
+
+impl DiskMap {
+    pub fn from(line: &[u8]) -> Result<Self> {
+        if line.is_empty() {
+            return Err(ParseError::in_line(line, line, "disk map is empty"));
+        }
+        let mut files = Vec::new();
+        let mut curr_id = 0;
+        let mut is_free = false;
+
+        parse::digits(line)?.into_iter().for_each(|digit| {
+            files.push(FileBlock {
+                id: if is_free { None } else { Some(curr_id) },
+                size: u32::from(digit),
+            });
+            if !is_free {
+                curr_id += 1
+            }
+            is_free = !is_free;
+        });
+
+        Ok(DiskMap { files })
+    }
+
+    pub fn compress(&self) -> Self {
+        self.compress_observed(|_| {})
+    }
+
+    /// Like [`compress`](DiskMap::compress), calling `on_move` with every
+    /// block moved.
+    pub fn compress_observed(&self, mut on_move: impl FnMut(Move)) -> Self {
+        let mut files: Vec<FileBlock> =
+            self.files.iter().flat_map(|block| block.expand()).collect();
+
+        let mut left = 0;
+        let mut right = files.len().saturating_sub(1);
+        while left < right {
+            // the bounds checks matter when there is no free space at all
+            while left < right && !files[left].is_free() {
+                left += 1;
+            }
+            while left < right && files[right].is_free() {
+                right -= 1;
+            }
+            if left >= right {
+                break;
+            }
+
+            (files[left], files[right]) = (files[right], files[left]);
+            if let Some(id) = files[left].id {
+                on_move(Move {
+                    id,
+                    from: right,
+                    to: left,
+                    size: 1,
+                });
+            }
+
+            left += 1;
+            right -= 1;
+        }
+
+        DiskMap { files }
+    }
+
+    /// The file id stored in every block of the disk, `None` for free blocks.
+    pub fn blocks(&self) -> Vec<Option<u32>> {
+        self.files
+            .iter()
+            .flat_map(|block| block.expand())
+            .map(|block| block.id)
+            .collect()
+    }
+
+    pub fn checksum(&self) -> Checked<u64> {
+        let files: Vec<FileBlock> = self.files.iter().flat_map(|block| block.expand()).collect();
+        files
+            .iter()
+            .enumerate()
+            .try_fold(0, |acc: u64, (i, block)| {
+                let Some(id) = block.id else {
+                    return Ok(acc);
+                };
+                (id as u64)
+                    .checked_mul(i as u64)
+                    .and_then(|x| acc.checked_add(x))
+                    .ok_or_else(|| {
+                        Overflow::new(format!(
+                            "adding file {id} at block {i} to the checksum {acc} overflows u64"
+                        ))
+                    })
+            })
+    }
+
+    fn free_block_with_min_size(size: u32, list: &[FileBlock]) -> Option<usize> {
+        for (i, blk) in list.iter().enumerate() {
+            if blk.is_free() && blk.size >= size {
+                return Some(i);
+            }
+        }
+        None
+    }
+
+    pub fn compress_no_fragmentation(&self) -> Self {
+        self.compress_no_fragmentation_observed(|_| {})
+    }
+
+    /// Like [`compress_no_fragmentation`](DiskMap::compress_no_fragmentation),
+    /// calling `on_move` with every file moved.
+    pub fn compress_no_fragmentation_observed(&self, mut on_move: impl FnMut(Move)) -> Self {
+        let mut files: Vec<Vec<FileBlock>> = self.files.iter().map(|block| vec![*block]).collect();
+        // where each of the original blocks starts on the disk
+        let starts: Vec<usize> = self
+            .files
+            .iter()
+            .scan(0, |start, block| {
+                let here = *start;
+                *start += block.size as usize;
+                Some(here)
+            })
+            .collect();
+
+        let mut right = files.len() - 1;
+        while right > 0 {
+            if files[right][0].is_free() {
+                right = right.saturating_sub(1);
+                continue;
+            }
+            // find left most free space
+            let space = files[right][0].size;
+            let mut left = 0;
+
+            while left < right {
+                let Some(i) = Self::free_block_with_min_size(space, &files[left]) else {
+                    left += 1;
+                    continue;
+                };
+
+                let extra = files[left][i].size - space;
+                files[left][i].id = files[right][0].id;
+                files[left][i].size = space;
+                files[right][0].id = None;
+                if let Some(id) = files[left][i].id {
+                    let before: u32 = files[left][..i].iter().map(|block| block.size).sum();
+                    on_move(Move {
+                        id,
+                        from: starts[right],
+                        to: starts[left] + before as usize,
+                        size: space,
+                    });
+                }
+
+                files[left].push(FileBlock {
+                    id: None,
+                    size: extra,
+                });
+                break;
+            }
+            right = right.saturating_sub(1);
+        }
+
+        DiskMap {
+            files: files.into_iter().flatten().collect::<Vec<FileBlock>>(),
+        }
+    }
+}
+
+/// Records compaction of the first disk in `contents` one move at a time,
+/// with part 1 moving single blocks and part 2 whole files. Blocks are laid
+/// out in rows to make a square, files shaded by id.
+pub fn record(
+    contents: &str,
+    part: Part,
+    palette: &Palette,
+    recorder: &mut Recorder,
+) -> Result<()> {
+    let disks = Day09::parse(contents)?;
+    let Some(disk) = disks.first() else {
+        return Err(ParseError::at_end(contents, "expected a disk map"));
+    };
+    let mut blocks = disk.blocks();
+    // a disk of nothing but empty files is still drawn, as a single cell
+    let width = ((blocks.len() as f64).sqrt().ceil() as usize).max(1);
+    let height = blocks.len().div_ceil(width).max(1);
+    let last_id = blocks.iter().flatten().max().copied().unwrap_or(0).max(1);
+    // a few dozen shades are enough to tell files apart and keep GIF
+    // frames within 256 colours
+    let shade = |id: u32| palette.gradient((id * 48 / last_id) as f64 / 48.0);
+    let draw = |blocks: &[Option<u32>]| {
+        let mut picture = Picture::new(height, width, palette.background);
+        for (k, block) in blocks.iter().enumerate() {
+            if let Some(id) = block {
+                picture.paint((k / width, k % width), shade(*id));
+            }
+        }
+        picture
+    };
+
+    recorder.step(|| draw(&blocks));
+    let on_move = |step: Move| {
+        for k in 0..step.size as usize {
+            blocks[step.to + k] = Some(step.id);
+            blocks[step.from + k] = None;
+        }
+        recorder.step(|| draw(&blocks));
+    };
+    match part {
+        Part::One => disk.compress_observed(on_move),
+        Part::Two => disk.compress_no_fragmentation_observed(on_move),
+    };
+    recorder.finish(|| draw(&blocks));
+    Ok(())
+}
+
+impl fmt::Display for DiskMap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for block in &self.files {
+            let writing = if block.is_free() {
+                ".".to_owned()
+            } else {
+                block.id.unwrap().to_string()
+            };
+            for _ in 0..block.size {
+                write!(f, "{writing} | ")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../inputs/examples/day09.txt");
+
+    #[test]
+    fn example_part1() {
+        let input = Day09::parse(EXAMPLE).unwrap();
+        assert_eq!(Day09::part1(&input), Ok(1928));
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day09::parse(EXAMPLE).unwrap();
+        assert_eq!(Day09::part2(&input), Ok(2858));
+    }
+
+    #[test]
+    fn disk_without_free_space() {
+        let input = Day09::parse("102\n").unwrap();
+        assert_eq!(Day09::part1(&input), Ok(3));
+        assert_eq!(Day09::part2(&input), Ok(3));
+    }
+
+    type Compaction = fn(&DiskMap, &mut dyn FnMut(Move)) -> DiskMap;
+
+    #[test]
+    fn replaying_moves_compacts_the_disk() {
+        let disk = DiskMap::from(EXAMPLE.trim().as_bytes()).unwrap();
+        let compactions: [Compaction; 2] = [
+            |disk, on_move| disk.compress_observed(on_move),
+            |disk, on_move| disk.compress_no_fragmentation_observed(on_move),
+        ];
+        for compact in compactions {
+            let mut blocks = disk.blocks();
+            let compacted = compact(&disk, &mut |step| {
+                for k in 0..step.size as usize {
+                    assert_eq!(blocks[step.from + k], Some(step.id));
+                    assert_eq!(blocks[step.to + k], None);
+                    blocks[step.to + k] = Some(step.id);
+                    blocks[step.from + k] = None;
+                }
+            });
+            assert_eq!(blocks, compacted.blocks());
+        }
+    }
+
+    #[test]
+    fn recording_needs_a_disk_but_not_blocks() {
+        let palette = Palette::default();
+        let mut frames = Vec::new();
+        let mut recorder = Recorder::new(1, 10, &mut frames);
+        assert!(record("", Part::One, &palette, &mut recorder).is_err());
+        record("000\n", Part::Two, &palette, &mut recorder).unwrap();
+        assert_eq!(frames[0].size(), (1, 1));
+    }
+}
@@ -0,0 +1,175 @@
+use crate::error::{Checked, Overflow, Result};
+use crate::geometry::Point;
+use crate::grid::Grid;
+use crate::render::{Mark, Palette, Picture};
+use crate::solution::Solution;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input<'a> = TopographicalMap;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(contents: &str) -> Result<Self::Input<'_>> {
+        TopographicalMap::from(contents)
+    }
+
+    fn part1(map: &Self::Input<'_>) -> Checked<u32> {
+        let (ans, _) = map.trailhead_scores()?;
+        Ok(ans)
+    }
+
+    fn part2(map: &Self::Input<'_>) -> Checked<u32> {
+        let (_, ans) = map.trailhead_scores()?;
+        Ok(ans)
+    }
+}
+
+#[derive(Debug)]
+pub struct TopographicalMap {
+    trailheads: Vec<(usize, usize)>,
+    heights: Grid<u32>,
+}
+
+impl TopographicalMap {
+    pub fn from(text: &str) -> Result<Self> {
+        let heights = Grid::parse(text.as_bytes(), |b| {
+            b.is_ascii_digit().then(|| u32::from(b - b'0'))
+        })?;
+        let trailheads = heights
+            .positions()
+            .filter(|&pos| heights[pos] == 0)
+            .collect();
+
+        Ok(Self {
+            trailheads,
+            heights,
+        })
+    }
+
+    /// The summits `trailhead` reaches and the trails leading to them. Every
+    /// step of a trail goes to one of four neighbours, so a single trailhead
+    /// has at most 4^9 trails and both counts fit in a u32.
+    pub fn bfs(&self, trailhead: (usize, usize)) -> (u32, u32) {
+        let mut bfs = VecDeque::new();
+        bfs.push_back(trailhead);
+
+        let mut peaks = HashSet::new();
+        let mut trails = 0;
+        while !bfs.is_empty() {
+            let Some(pos) = bfs.pop_front() else {
+                panic!("should not get None from an non-empty deque");
+            };
+            let curr = self.heights[pos];
+            if curr == 9 {
+                peaks.insert(pos);
+                trails += 1;
+                continue;
+            }
+
+            for next in self.heights.neighbours4(pos) {
+                if curr + 1 == self.heights[next] {
+                    bfs.push_back(next);
+                }
+            }
+        }
+        (peaks.len() as u32, trails)
+    }
+
+    pub fn trailhead_scores(&self) -> Checked<(u32, u32)> {
+        self.trailheads
+            .iter()
+            .try_fold((0, 0), |acc: (u32, u32), &th| {
+                let (peaks, trails) = self.bfs(th);
+                let overflow = |score| {
+                    Overflow::new(format!(
+                        "adding the {score} of the trailhead at row {}, column {} overflows u32",
+                        th.0 + 1,
+                        th.1 + 1
+                    ))
+                };
+                Ok((
+                    acc.0.checked_add(peaks).ok_or_else(|| overflow("score"))?,
+                    acc.1
+                        .checked_add(trails)
+                        .ok_or_else(|| overflow("rating"))?,
+                ))
+            })
+    }
+
+    /// Every position on at least one hiking trail: reachable going up from a
+    /// trailhead and able to reach a summit.
+    pub fn trail_cells(&self) -> HashSet<(usize, usize)> {
+        let from_trailheads = self.climb(self.trailheads.iter().copied(), 1);
+        let summits = self
+            .heights
+            .positions()
+            .filter(|&pos| self.heights[pos] == 9);
+        let from_summits = self.climb(summits, -1);
+        from_trailheads
+            .intersection(&from_summits)
+            .copied()
+            .collect()
+    }
+
+    /// The positions reachable from `starts` moving `step` in height at a time.
+    fn climb(
+        &self,
+        starts: impl Iterator<Item = (usize, usize)>,
+        step: i32,
+    ) -> HashSet<(usize, usize)> {
+        let mut seen: HashSet<_> = starts.collect();
+        let mut bfs: VecDeque<_> = seen.iter().copied().collect();
+        while let Some(pos) = bfs.pop_front() {
+            let wanted = self.heights[pos] as i32 + step;
+            for next in self.heights.neighbours4(pos) {
+                if self.heights[next] as i32 == wanted && seen.insert(next) {
+                    bfs.push_back(next);
+                }
+            }
+        }
+        seen
+    }
+}
+
+/// Heights shaded from the first accent at 0 to the last at 9, with every
+/// position on a hiking trail dotted.
+pub fn render(contents: &str, palette: &Palette) -> Result<Picture> {
+    let map = TopographicalMap::from(contents)?;
+    let mut picture = Picture::from_grid(&map.heights, |&h| palette.gradient(h as f64 / 9.0));
+    let trails = map.trail_cells().into_iter().map(Point::from);
+    picture.overlay(trails, palette.foreground, Mark::Dot);
+    Ok(picture)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../inputs/examples/day10.txt");
+
+    #[test]
+    fn example_part1() {
+        let input = Day10::parse(EXAMPLE).unwrap();
+        assert_eq!(Day10::part1(&input), Ok(36));
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day10::parse(EXAMPLE).unwrap();
+        assert_eq!(Day10::part2(&input), Ok(81));
+    }
+
+    #[test]
+    fn trails_lead_from_trailheads_to_summits() {
+        // the second row climbs from 1 to 4 and stops there
+        let map = Day10::parse("0123456789\n1234000000\n").unwrap();
+        let trails = map.trail_cells();
+        assert_eq!(trails, (0..10).map(|j| (0, j)).collect());
+    }
+}
@@ -0,0 +1,385 @@
+use crate::error::{Checked, Overflow, ParseError, Result};
+use crate::parse;
+use crate::record::Recorder;
+use crate::render::{Palette, Picture, Rgb};
+use crate::solution::{Part, Solution, SolveError};
+use rayon::prelude::*;
+use std::collections::HashMap;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input<'a> = StoneLine;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(contents: &str) -> Result<Self::Input<'_>> {
+        StoneLine::from(contents.as_bytes())
+    }
+
+    fn part1(stones: &Self::Input<'_>) -> Checked<u64> {
+        let stones = (0..25).try_fold(stones.clone(), |acc, _| acc.blink())?;
+        Ok(stones.count())
+    }
+
+    fn part2(stones: &Self::Input<'_>) -> Checked<u64> {
+        stones.better_blink(75)
+    }
+}
+
+/// The number of decimal digits in `num`.
+pub fn log_10(num: u64) -> u64 {
+    // ilog10 rather than multiplying up to num, which overflows past 10^19
+    u64::from(num.checked_ilog10().unwrap_or(0)) + 1
+}
+
+fn split_if_even(num: u64) -> Option<(u64, u64)> {
+    let digits = log_10(num);
+
+    if digits % 2 == 1 {
+        return None;
+    }
+    let divider = 10_u64.pow((digits / 2) as u32);
+    let left = num / (divider);
+    let right = num % (divider);
+
+    Some((left, right))
+}
+
+pub fn stone_morph(stone: u64) -> Checked<Vec<u64>> {
+    if stone == 0 {
+        return Ok(vec![1]);
+    }
+    if let Some((a, b)) = split_if_even(stone) {
+        return Ok(vec![a, b]);
+    }
+    match stone.checked_mul(2024) {
+        Some(next) => Ok(vec![next]),
+        None => Err(Overflow::new(format!(
+            "stone {stone} times 2024 overflows u64"
+        ))),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StoneLine {
+    stones: Vec<u64>,
+}
+
+impl StoneLine {
+    pub fn from(line: &[u8]) -> Result<Self> {
+        let stones: Vec<u64> = parse::integers(line, b' ')?;
+        if stones.is_empty() {
+            return Err(ParseError::at_end(line, "expected at least one stone"));
+        }
+        Ok(Self { stones })
+    }
+
+    pub fn blink(&self) -> Checked<Self> {
+        let stones: Vec<Vec<u64>> = self
+            .stones
+            .par_iter()
+            .map(|stone| stone_morph(*stone))
+            .collect::<Checked<_>>()?;
+
+        Ok(Self {
+            stones: stones.into_iter().flatten().collect(),
+        })
+    }
+
+    pub fn count(&self) -> u64 {
+        self.stones.len() as u64
+    }
+
+    pub fn amortized_blink(
+        stone: u64,
+        iterations: u64,
+        amortization: &mut HashMap<u64, HashMap<u64, u64>>,
+    ) -> Checked<u64> {
+        // amortized[stone] = iterations -> count
+        if iterations == 0 {
+            return Ok(1);
+        }
+
+        if let Some(map) = amortization.get(&stone) {
+            if let Some(amortized_count) = map.get(&iterations) {
+                return Ok(*amortized_count);
+            }
+        }
+
+        let mut count: u64 = 0;
+        for next in stone_morph(stone)? {
+            let next_count = Self::amortized_blink(next, iterations - 1, amortization)?;
+            count = count.checked_add(next_count).ok_or_else(|| {
+                Overflow::new(format!(
+                    "stone {stone} splits into more than u64::MAX stones in {iterations} blinks"
+                ))
+            })?;
+        }
+
+        let prev = amortization
+            .entry(stone)
+            .or_default()
+            .insert(iterations, count);
+
+        if let Some(count_prime) = prev {
+            if count != count_prime {
+                panic!("Should not be possible to blink a stone to different counts given same iteration amount")
+            }
+        }
+        Ok(count)
+    }
+
+    pub fn better_blink(&self, iterations: u64) -> Checked<u64> {
+        let mut amortization: HashMap<u64, HashMap<u64, u64>> = HashMap::new();
+
+        let mut ans: u64 = 0;
+        for stone in &self.stones {
+            let count = Self::amortized_blink(*stone, iterations, &mut amortization)?;
+            ans = ans.checked_add(count).ok_or_else(|| {
+                Overflow::new(format!(
+                    "more than u64::MAX stones after {iterations} blinks"
+                ))
+            })?;
+        }
+
+        Ok(ans)
+    }
+}
+
+/// How many stones of each line a recorded frame shows.
+const RECORDED_STONES: usize = 128;
+
+/// Records the 25 blinks of part 1. Frame `k` shows the line after each of the
+/// first `k` blinks, one row per blink, every stone shaded by its number of
+/// digits; lines longer than [`RECORDED_STONES`] are cut off.
+pub fn record(
+    contents: &str,
+    palette: &Palette,
+    recorder: &mut Recorder,
+) -> std::result::Result<(), SolveError> {
+    const BLINKS: usize = 25;
+    let mut line = StoneLine::from(contents.as_bytes())?;
+    let row = |line: &StoneLine| -> Vec<Rgb> {
+        let stones = line.stones.iter().take(RECORDED_STONES);
+        stones
+            .map(|&stone| palette.gradient((log_10(stone) - 1) as f64 / 19.0))
+            .collect()
+    };
+    let draw = |rows: &[Vec<Rgb>]| {
+        let mut picture = Picture::new(BLINKS + 1, RECORDED_STONES, palette.background);
+        for (i, row) in rows.iter().enumerate() {
+            for (j, &colour) in row.iter().enumerate() {
+                picture.paint((i, j), colour);
+            }
+        }
+        picture
+    };
+
+    let mut rows = vec![row(&line)];
+    recorder.step(|| draw(&rows));
+    for _ in 0..BLINKS {
+        line = line.blink().map_err(|overflow| SolveError::Overflow {
+            part: Part::One,
+            overflow,
+        })?;
+        rows.push(row(&line));
+        recorder.step(|| draw(&rows));
+    }
+    recorder.finish(|| draw(&rows));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../../inputs/examples/day11.txt");
+
+    #[test]
+    fn digit_count_test() {
+        let x = 1;
+        assert_eq!(log_10(x), 1);
+
+        let x = 10;
+        assert_eq!(log_10(x), 2);
+
+        let x = 100;
+        assert_eq!(log_10(x), 3);
+
+        let x = 1_000;
+        assert_eq!(log_10(x), 4);
+
+        assert_eq!(log_10(0), 1);
+        assert_eq!(log_10(u64::MAX), 20);
+    }
+
+    #[test]
+    fn test_split() {
+        let x = 2024;
+        assert_eq!(split_if_even(x), Some((20, 24)));
+
+        let x = 99;
+        assert_eq!(split_if_even(x), Some((9, 9)));
+
+        let x = 2097446912;
+        assert_eq!(split_if_even(x), Some((20974, 46912)));
+
+        let x = 209744691;
+        assert_eq!(split_if_even(x), None);
+
+        let x = 9;
+        assert_eq!(split_if_even(x), None);
+
+        let x = 111;
+        assert_eq!(split_if_even(x), None);
+    }
+
+    #[test]
+    pub fn example_blink_1() {
+        let curr = StoneLine {
+            stones: vec![125, 17],
+        };
+        let expected = StoneLine {
+            stones: vec![253000, 1, 7],
+        };
+        assert_eq!(curr.blink(), Ok(expected.clone()));
+    }
+
+    #[test]
+    pub fn example_blink_2() {
+        let curr = StoneLine {
+            stones: vec![253000, 1, 7],
+        };
+        let expected = StoneLine {
+            stones: vec![253, 0, 2024, 14168],
+        };
+        assert_eq!(curr.blink(), Ok(expected.clone()));
+        assert_eq!(curr.blink(), Ok(expected.clone()));
+    }
+
+    #[test]
+    pub fn example_blink_3() {
+        let curr = StoneLine {
+            stones: vec![253, 0, 2024, 14168],
+        };
+        let expected = StoneLine {
+            stones: vec![512072, 1, 20, 24, 28676032],
+        };
+        assert_eq!(curr.blink(), Ok(expected.clone()));
+    }
+
+    #[test]
+    pub fn example_blink_4() {
+        let curr = StoneLine {
+            stones: vec![512072, 1, 20, 24, 28676032],
+        };
+        let expected = StoneLine {
+            stones: vec![512, 72, 2024, 2, 0, 2, 4, 2867, 6032],
+        };
+        assert_eq!(curr.blink(), Ok(expected.clone()));
+    }
+
+    #[test]
+    pub fn example_blink_5() {
+        let curr = StoneLine {
+            stones: vec![512, 72, 2024, 2, 0, 2, 4, 2867, 6032],
+        };
+        let expected = StoneLine {
+            stones: vec![1036288, 7, 2, 20, 24, 4048, 1, 4048, 8096, 28, 67, 60, 32],
+        };
+        assert_eq!(curr.blink(), Ok(expected.clone()));
+    }
+    #[test]
+    pub fn example_blink_6() {
+        let curr = StoneLine {
+            stones: vec![1036288, 7, 2, 20, 24, 4048, 1, 4048, 8096, 28, 67, 60, 32],
+        };
+        let expected = StoneLine {
+            stones: vec![
+                2097446912, 14168, 4048, 2, 0, 2, 4, 40, 48, 2024, 40, 48, 80, 96, 2, 8, 6, 7, 6,
+                0, 3, 2,
+            ],
+        };
+        assert_eq!(curr.blink(), Ok(expected.clone()));
+    }
+
+    #[test]
+    fn example_part1() {
+        let input = Day11::parse(EXAMPLE).unwrap();
+        assert_eq!(Day11::part1(&input), Ok(55312));
+    }
+
+    #[test]
+    fn example_part2() {
+        // the puzzle only publishes the part 1 answer for the example, so the
+        // memoised count is checked against blinking every stone for as many
+        // blinks as that stays quick
+        let input = Day11::parse(EXAMPLE).unwrap();
+        assert_eq!(naive_count(&input.stones, 25), 55312);
+        for blinks in 0..=30 {
+            assert_eq!(
+                input.better_blink(blinks as u64),
+                Ok(naive_count(&input.stones, blinks)),
+                "{blinks} blinks"
+            );
+        }
+    }
+
+    #[test]
+    fn example_part2_snapshot() {
+        // a regression snapshot of the solver's own answer for 75 blinks, which
+        // is too many to check naively
+        let input = Day11::parse(EXAMPLE).unwrap();
+        assert_eq!(Day11::part2(&input), Ok(65601038650482));
+    }
+
+    #[test]
+    fn overflowing_stones_are_an_error() {
+        // 19 digits, so the stone is multiplied rather than split
+        let input = Day11::parse("9999999999999999999").unwrap();
+        let e = Day11::part1(&input).unwrap_err();
+        assert_eq!(
+            e.context,
+            "stone 9999999999999999999 times 2024 overflows u64"
+        );
+        assert_eq!(Day11::part2(&input), Err(e));
+    }
+
+    /// Reference answer: apply the rules to every stone, one blink at a time,
+    /// splitting numbers through their decimal text.
+    fn naive_count(stones: &[u64], blinks: usize) -> u64 {
+        let mut stones = stones.to_vec();
+        for _ in 0..blinks {
+            stones = stones
+                .into_iter()
+                .flat_map(|stone| {
+                    let text = stone.to_string();
+                    if stone == 0 {
+                        vec![1]
+                    } else if text.len() % 2 == 0 {
+                        let (left, right) = text.split_at(text.len() / 2);
+                        vec![left.parse().unwrap(), right.parse().unwrap()]
+                    } else {
+                        vec![stone * 2024]
+                    }
+                })
+                .collect();
+        }
+        stones.len() as u64
+    }
+
+    proptest! {
+        #[test]
+        fn better_blink_matches_naive(
+            stones in prop::collection::vec(0..100_000u64, 1..4),
+            blinks in 0..20usize,
+        ) {
+            let line = StoneLine { stones: stones.clone() };
+            prop_assert_eq!(line.better_blink(blinks as u64), Ok(naive_count(&stones, blinks)));
+        }
+    }
+}
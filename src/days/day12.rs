@@ -0,0 +1,308 @@
+use crate::error::{Checked, Overflow, Result};
+use crate::explore::Exploration;
+use crate::geometry::{Direction, Point};
+use crate::grid::Grid;
+use crate::render::{Mark, Palette, Picture, Rgb};
+use crate::solution::Solution;
+use std::collections::{HashSet, VecDeque};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input<'a> = Garden;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(contents: &str) -> Result<Self::Input<'_>> {
+        Garden::from(contents)
+    }
+
+    fn part1(plots: &Self::Input<'_>) -> Checked<u64> {
+        let (ans, _) = plots.fencing_price()?;
+        Ok(ans)
+    }
+
+    fn part2(plots: &Self::Input<'_>) -> Checked<u64> {
+        let (_, ans) = plots.fencing_price()?;
+        Ok(ans)
+    }
+}
+
+pub struct Garden {
+    grid: Grid<u8>,
+}
+
+/// A connected group of plots growing the same plant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    pub plant: char,
+    /// The first plot of the region in reading order.
+    pub start: (usize, usize),
+    pub plots: HashSet<(usize, usize)>,
+    pub area: u64,
+    pub perimeter: u64,
+    pub sides: u64,
+}
+
+impl Garden {
+    pub fn from(text: &str) -> Result<Self> {
+        let grid = Grid::from_bytes(text.as_bytes())?;
+        Ok(Self { grid })
+    }
+
+    pub fn fencing_price(&self) -> Checked<(u64, u64)> {
+        let mut explored: HashSet<(usize, usize)> = HashSet::new();
+        let mut ans1 = 0;
+        let mut ans2 = 0;
+        for (i, j) in self.grid.positions() {
+            if explored.contains(&(i, j)) {
+                continue;
+            }
+            let (area, perimeter, sides) = self.explore(i, j, &mut explored);
+
+            ans1 = add_price(ans1, area, perimeter, (i, j))?;
+            ans2 = add_price(ans2, area, sides, (i, j))?;
+        }
+        Ok((ans1, ans2))
+    }
+
+    /// Every region of the garden, in the order they are found scanning the
+    /// rows.
+    pub fn regions(&self) -> Vec<Region> {
+        let mut explored: HashSet<(usize, usize)> = HashSet::new();
+        let mut regions = Vec::new();
+        for (i, j) in self.grid.positions() {
+            if explored.contains(&(i, j)) {
+                continue;
+            }
+            let mut plots = HashSet::new();
+            let (area, perimeter, sides) = self.explore(i, j, &mut plots);
+            explored.extend(plots.iter().copied());
+            regions.push(Region {
+                plant: char::from(self.grid[(i, j)]),
+                start: (i, j),
+                plots,
+                area,
+                perimeter,
+                sides,
+            });
+        }
+        regions
+    }
+
+    /// Picks an accent of `palette` for every region of `regions` so that
+    /// neighbouring regions never share one while there are accents to spare.
+    fn colour_regions(&self, regions: &[Region], palette: &Palette) -> Vec<Rgb> {
+        let mut colours: Grid<Option<usize>> = self.grid.map(|_| None);
+        let mut picked = Vec::with_capacity(regions.len());
+        for region in regions {
+            let taken: HashSet<usize> = region
+                .plots
+                .iter()
+                .flat_map(|&pos| self.grid.neighbours4(pos))
+                .filter_map(|pos| colours[pos])
+                .collect();
+            let colour = (0..palette.accents.len())
+                .find(|k| !taken.contains(k))
+                .unwrap_or(0);
+            for &pos in &region.plots {
+                colours[pos] = Some(colour);
+            }
+            picked.push(palette.accent(colour));
+        }
+        picked
+    }
+
+    /// Whether the plot `offset` away from `pos` exists and is in `group`.
+    fn is_same(&self, pos: (usize, usize), offset: Point, group: u8) -> bool {
+        self.grid
+            .offset(pos, offset)
+            .is_some_and(|p| self.grid[p] == group)
+    }
+
+    fn explore(
+        &self,
+        x: usize,
+        y: usize,
+        explored: &mut HashSet<(usize, usize)>,
+    ) -> (u64, u64, u64) {
+        let mut queue = VecDeque::new();
+        let mut area = 0;
+        let mut perimeter = 0;
+        let mut corners = 0;
+        let group = self.grid[(x, y)];
+        queue.push_back((x, y));
+
+        while let Some((i, j)) = queue.pop_front() {
+            if explored.contains(&(i, j)) {
+                continue;
+            }
+            area += 1;
+            corners += self.corner_count((i, j), group);
+            explored.insert((i, j));
+
+            // every side not shared with the same group needs a fence
+            perimeter += 4;
+            for next in self.grid.neighbours4((i, j)) {
+                if self.grid[next] == group {
+                    queue.push_back(next);
+                    perimeter -= 1;
+                }
+            }
+        }
+
+        (area, perimeter, corners)
+    }
+
+    fn is_corner(&self, diagonal: bool, vertical: bool, horizontal: bool) -> bool {
+        match (diagonal, vertical, horizontal) {
+            (false, a, b) if a == b => true,
+            (false, a, b) if a != b => false,
+            (true, false, false) => true,
+            (_, _, _) => false,
+        }
+    }
+
+    fn corner_count(&self, pos: (usize, usize), group: u8) -> u64 {
+        let mut ans = 0;
+
+        for diagonal in Direction::DIAGONAL {
+            let offset = diagonal.offset();
+            if self.is_corner(
+                self.is_same(pos, offset, group),
+                self.is_same(pos, Point::new(offset.row, 0), group),
+                self.is_same(pos, Point::new(0, offset.col), group),
+            ) {
+                ans += 1;
+            }
+        }
+
+        ans
+    }
+}
+
+/// Every region in its own colour, picked so that neighbouring regions never
+/// share one while the palette has accents to spare.
+pub fn render(contents: &str, palette: &Palette) -> Result<Picture> {
+    let garden = Garden::from(contents)?;
+    let regions = garden.regions();
+    let colours = garden.colour_regions(&regions, palette);
+    let mut picture = Picture::new(garden.grid.n(), garden.grid.m(), palette.background);
+    for (region, &colour) in regions.iter().zip(&colours) {
+        for &pos in &region.plots {
+            picture.paint(pos, colour);
+        }
+    }
+    Ok(picture)
+}
+
+/// `total` plus the price of fencing the region starting at `start`: its
+/// `area` times the `length` of fence, counted in edges or in sides.
+fn add_price(total: u64, area: u64, length: u64, start: (usize, usize)) -> Checked<u64> {
+    area.checked_mul(length)
+        .and_then(|price| total.checked_add(price))
+        .ok_or_else(|| {
+            Overflow::new(format!(
+                "adding the price {area} * {length} of the region at row {}, column {} to {total} overflows u64",
+                start.0 + 1,
+                start.1 + 1
+            ))
+        })
+}
+
+/// Explores the garden one region at a time, in the order the solver prices
+/// them, with the plots along each region's fence outlined.
+pub fn explore(contents: &str, palette: &Palette) -> Result<Exploration> {
+    let garden = Garden::from(contents)?;
+    let regions = garden.regions();
+    let colours = garden.colour_regions(&regions, palette);
+
+    let mut exploration = Exploration::new(
+        "Day 12: Garden Groups",
+        garden.grid.map(|&b| char::from(b)),
+        palette.background,
+        |_| palette.foreground,
+    );
+    let filled = exploration.layer("regions", Mark::Fill);
+    let fenced = exploration.layer("fences", Mark::Outline);
+    exploration.step(format!("{} regions to price", regions.len()), None);
+
+    // running totals, shown as overflowing from the region that overflows on
+    let (mut price, mut discounted) = (Ok(0), Ok(0));
+    let total = |total: &Checked<u64>| match total {
+        Ok(total) => total.to_string(),
+        Err(_) => "over u64::MAX".to_string(),
+    };
+    for (k, (region, &colour)) in regions.iter().zip(&colours).enumerate() {
+        price = price.and_then(|p| add_price(p, region.area, region.perimeter, region.start));
+        discounted = discounted.and_then(|d| add_price(d, region.area, region.sides, region.start));
+        exploration.step(
+            format!(
+                "region {}/{} of {}: area {}, perimeter {}, {} sides; price {}, discounted {}",
+                k + 1,
+                regions.len(),
+                region.plant,
+                region.area,
+                region.perimeter,
+                region.sides,
+                total(&price),
+                total(&discounted),
+            ),
+            Some((Point::from(region.start), region.plant)),
+        );
+        let plots = || region.plots.iter().map(|&pos| Point::from(pos));
+        exploration.reveal(filled, plots(), colour);
+        let fence = plots().filter(|&plot| {
+            Direction::ORTHOGONAL
+                .iter()
+                .any(|d| garden.grid.get_point(d.forward(plot)) != Some(&(region.plant as u8)))
+        });
+        exploration.reveal(fenced, fence, palette.foreground);
+    }
+    Ok(exploration)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../inputs/examples/day12.txt");
+
+    #[test]
+    fn example_part1() {
+        let input = Day12::parse(EXAMPLE).unwrap();
+        assert_eq!(Day12::part1(&input), Ok(1930));
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day12::parse(EXAMPLE).unwrap();
+        assert_eq!(Day12::part2(&input), Ok(1206));
+    }
+
+    #[test]
+    fn example_regions() {
+        let garden = Day12::parse(EXAMPLE).unwrap();
+        let regions = garden.regions();
+        assert_eq!(regions.len(), 11);
+        assert_eq!(regions.iter().map(|r| r.plots.len()).sum::<usize>(), 100);
+        let prices = regions
+            .iter()
+            .map(|r| (r.area * r.perimeter, r.area * r.sides));
+        let (price, discounted) = prices.fold((0, 0), |(a, b), (c, d)| (a + c, b + d));
+        assert_eq!((price, discounted), (1930, 1206));
+    }
+
+    #[test]
+    fn overflowing_prices_are_an_error() {
+        assert_eq!(add_price(1, 2, 3, (0, 0)), Ok(7));
+        let overflow = add_price(u64::MAX - 5, 2, 3, (1, 4)).unwrap_err();
+        assert_eq!(
+            overflow.to_string(),
+            "arithmetic overflow: adding the price 2 * 3 of the region at row 2, column 5 to 18446744073709551610 overflows u64"
+        );
+        assert!(add_price(0, u64::MAX, 2, (0, 0)).is_err());
+    }
+}
@@ -0,0 +1,161 @@
+//! The errors returned by input parsers and by solvers whose arithmetic
+//! outgrows their answer type.
+
+use std::error::Error;
+use std::fmt;
+
+/// A problem found while parsing puzzle input, with enough location to find
+/// the offending text in the file.
+///
+/// Lines and columns are 1-based and columns count characters, matching what
+/// editors display. Parsers that only see a single line report line 1; the
+/// caller that knows where the line came from fixes it up with
+/// [`on_line`](ParseError::on_line), and the runner fills in the file name
+/// with [`in_file`](ParseError::in_file).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<String>,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+pub type Result<T> = std::result::Result<T, ParseError>;
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, text: &str, message: impl Into<String>) -> Self {
+        Self {
+            file: None,
+            line,
+            column,
+            text: text.to_owned(),
+            message: message.into(),
+        }
+    }
+
+    /// An error about `part`, which must be a slice of `line`. Both may be
+    /// given as text or as the raw bytes of the input.
+    pub fn in_line<L, P>(line: &L, part: &P, message: impl Into<String>) -> Self
+    where
+        L: AsRef<[u8]> + ?Sized,
+        P: AsRef<[u8]> + ?Sized,
+    {
+        let (line, part) = (line.as_ref(), part.as_ref());
+        let text = String::from_utf8_lossy(part);
+        Self::new(1, column_of(line, part), &text, message)
+    }
+
+    /// An error for input that stopped before something required, pointing
+    /// just past the last line of `contents`.
+    pub fn at_end(contents: &(impl AsRef<[u8]> + ?Sized), message: impl Into<String>) -> Self {
+        let contents = contents.as_ref();
+        let breaks = contents.iter().filter(|&&b| b == b'\n').count();
+        let unterminated = !contents.is_empty() && !contents.ends_with(b"\n");
+        Self::new(breaks + usize::from(unterminated) + 1, 1, "", message)
+    }
+
+    /// Re-bases the column of an error reported against `part`, a slice of
+    /// `line`, so it counts from the start of `line` instead.
+    pub fn within<L, P>(mut self, line: &L, part: &P) -> Self
+    where
+        L: AsRef<[u8]> + ?Sized,
+        P: AsRef<[u8]> + ?Sized,
+    {
+        self.column += column_of(line.as_ref(), part.as_ref()) - 1;
+        self
+    }
+
+    pub fn on_line(mut self, line: usize) -> Self {
+        self.line = line;
+        self
+    }
+
+    pub fn in_file(mut self, file: impl Into<String>) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+}
+
+/// The 1-based character column at which `part` starts inside `line`.
+///
+/// Falls back to column 1 if `part` is not actually a slice of `line`.
+fn column_of(line: &[u8], part: &[u8]) -> usize {
+    let offset = (part.as_ptr() as usize).wrapping_sub(line.as_ptr() as usize);
+    match line.get(..offset) {
+        // every character starts with exactly one byte that is not a UTF-8
+        // continuation byte
+        Some(before) => before.iter().filter(|&&b| b & 0xC0 != 0x80).count() + 1,
+        None => 1,
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let file = self.file.as_deref().unwrap_or("<input>");
+        write!(f, "{file}:{}:{}: {}", self.line, self.column, self.message)?;
+        if !self.text.is_empty() {
+            write!(f, ": {:?}", self.text)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+/// A computation that does not fit in the type the answer is kept in.
+///
+/// Solvers use checked arithmetic wherever values from the input are
+/// multiplied or summed, so an input too big for them is reported rather than
+/// answered wrongly in release builds, where overflow silently wraps.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Overflow {
+    /// What was being computed, with the values involved.
+    pub context: String,
+}
+
+/// What a puzzle part returns.
+pub type Checked<T> = std::result::Result<T, Overflow>;
+
+impl Overflow {
+    pub fn new(context: impl Into<String>) -> Self {
+        Self {
+            context: context.into(),
+        }
+    }
+}
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "arithmetic overflow: {}", self.context)
+    }
+}
+
+impl Error for Overflow {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn columns_count_characters() {
+        let line = "7 6 x 2";
+        let part = line.split(' ').nth(2).unwrap();
+        let e = ParseError::in_line(line, part, "invalid number").on_line(4);
+        assert_eq!((e.line, e.column), (4, 5));
+        assert_eq!(e.to_string(), "<input>:4:5: invalid number: \"x\"");
+    }
+
+    #[test]
+    fn byte_columns_count_characters() {
+        let line = "é 7 x".as_bytes();
+        let e = ParseError::in_line(line, &line[5..], "invalid number");
+        assert_eq!((e.column, e.text.as_str()), (5, "x"));
+    }
+
+    #[test]
+    fn truncated_input_points_past_the_end() {
+        let e = ParseError::at_end("a\nb\n", "missing updates").in_file("day05.txt");
+        assert_eq!(e.to_string(), "day05.txt:3:1: missing updates");
+    }
+}
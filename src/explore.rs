@@ -0,0 +1,242 @@
+//! Step-by-step explorations of grid solvers for the interactive explorer.
+//!
+//! An [`Exploration`] is the grid of a day plus the steps its algorithm took.
+//! Each step reveals cells on named layers (the guard's path, a garden
+//! region, ...), so the state after any step, with any set of layers shown, can
+//! be looked up directly when moving backwards and forwards through a run.
+
+use crate::days::*;
+use crate::error::ParseError;
+use crate::geometry::Point;
+use crate::grid::Grid;
+use crate::render::{Mark, Palette, Rgb};
+
+/// The days that can be explored.
+pub const DAYS: &[u8] = &[6, 12];
+
+/// A set of cells revealed step by step and drawn with the same mark.
+#[derive(Debug, Clone)]
+pub struct Layer {
+    pub name: String,
+    pub mark: Mark,
+    /// The step each cell was first revealed at, and its colour.
+    revealed: Grid<Option<(usize, Rgb)>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    /// What happened in this step.
+    pub note: String,
+    /// Where the algorithm is, drawn as the given character.
+    pub cursor: Option<(Point, char)>,
+}
+
+/// How a single grid cell looks at some step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    pub glyph: char,
+    pub fg: Rgb,
+    pub bg: Rgb,
+    /// Whether the cell is emphasised: outlined by a layer or under the cursor.
+    pub bold: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct Exploration {
+    pub title: String,
+    glyphs: Grid<char>,
+    colours: Grid<Rgb>,
+    background: Rgb,
+    layers: Vec<Layer>,
+    steps: Vec<Step>,
+}
+
+impl Exploration {
+    /// An exploration of `glyphs`, each drawn in the colour `colour` picks on
+    /// `background`.
+    pub fn new(
+        title: impl Into<String>,
+        glyphs: Grid<char>,
+        background: Rgb,
+        colour: impl FnMut(&char) -> Rgb,
+    ) -> Self {
+        Self {
+            title: title.into(),
+            colours: glyphs.map(colour),
+            glyphs,
+            background,
+            layers: Vec::new(),
+            steps: Vec::new(),
+        }
+    }
+
+    /// Adds a layer, returning the index to reveal cells on it with.
+    pub fn layer(&mut self, name: impl Into<String>, mark: Mark) -> usize {
+        self.layers.push(Layer {
+            name: name.into(),
+            mark,
+            revealed: self.glyphs.map(|_| None),
+        });
+        self.layers.len() - 1
+    }
+
+    /// Starts a new step.
+    pub fn step(&mut self, note: impl Into<String>, cursor: Option<(Point, char)>) {
+        self.steps.push(Step {
+            note: note.into(),
+            cursor,
+        });
+    }
+
+    /// Reveals `cells` on `layer` in the current step. Cells already revealed
+    /// keep the step and colour they were first revealed with.
+    pub fn reveal(&mut self, layer: usize, cells: impl IntoIterator<Item = Point>, colour: Rgb) {
+        let step = self.steps.len().saturating_sub(1);
+        let revealed = &mut self.layers[layer].revealed;
+        for cell in cells {
+            if let Some(pos) = revealed.index_of(cell) {
+                revealed[pos].get_or_insert((step, colour));
+            }
+        }
+    }
+
+    pub fn layers(&self) -> &[Layer] {
+        &self.layers
+    }
+
+    pub fn steps(&self) -> &[Step] {
+        &self.steps
+    }
+
+    /// The size of the grid, as `(rows, columns)`.
+    pub fn size(&self) -> (usize, usize) {
+        (self.glyphs.n(), self.glyphs.m())
+    }
+
+    /// How the cell at `pos` looks after `step`, with only the layers whose
+    /// entry in `shown` is true drawn. Later layers are drawn over earlier
+    /// ones.
+    pub fn cell(&self, pos: (usize, usize), step: usize, shown: &[bool]) -> Cell {
+        let mut cell = Cell {
+            glyph: self.glyphs[pos],
+            fg: self.colours[pos],
+            bg: self.background,
+            bold: false,
+        };
+        let layers = self.layers.iter().zip(shown).filter(|(_, &shown)| shown);
+        for (layer, _) in layers {
+            let Some((revealed, colour)) = layer.revealed[pos] else {
+                continue;
+            };
+            if revealed > step {
+                continue;
+            }
+            match layer.mark {
+                Mark::Fill => cell.bg = colour,
+                Mark::Dot => {
+                    cell.glyph = '•';
+                    cell.fg = colour;
+                }
+                Mark::Outline => {
+                    cell.fg = colour;
+                    cell.bold = true;
+                }
+            }
+        }
+        let cursor = self.steps.get(step).and_then(|step| step.cursor);
+        if let Some((at, glyph)) = cursor {
+            if self.glyphs.index_of(at) == Some(pos) {
+                cell.glyph = glyph;
+                cell.bold = true;
+            }
+        }
+        cell
+    }
+}
+
+/// Builds the exploration of `day` from `input`, or returns `None` if the day
+/// cannot be explored.
+pub fn explore(day: u8, input: &str, palette: &Palette) -> Option<Result<Exploration, ParseError>> {
+    let exploration = match day {
+        6 => day06::explore(input, palette),
+        12 => day12::explore(input, palette),
+        _ => return None,
+    };
+    Some(exploration)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input;
+
+    const RED: Rgb = Rgb(255, 0, 0);
+    const BLUE: Rgb = Rgb(0, 0, 255);
+    const BLACK: Rgb = Rgb(0, 0, 0);
+    const WHITE: Rgb = Rgb(255, 255, 255);
+
+    fn exploration() -> Exploration {
+        let grid = Grid::from_chars("...\n.#.\n").unwrap();
+        let mut exploration = Exploration::new("test", grid, BLACK, |_| WHITE);
+        let path = exploration.layer("path", Mark::Dot);
+        let region = exploration.layer("region", Mark::Fill);
+        exploration.step("start", Some((Point::new(0, 0), '>')));
+        exploration.reveal(path, [Point::new(0, 0)], RED);
+        exploration.step("move", Some((Point::new(0, 1), '>')));
+        exploration.reveal(path, [Point::new(0, 1), Point::new(0, 0)], BLUE);
+        exploration.reveal(region, [Point::new(0, 1), Point::new(-1, 0)], BLUE);
+        exploration
+    }
+
+    #[test]
+    fn cells_appear_at_the_step_revealing_them() {
+        let exploration = exploration();
+        let shown = [true, true];
+        assert_eq!(exploration.cell((0, 1), 0, &shown).glyph, '.');
+        let cell = exploration.cell((0, 1), 1, &shown);
+        assert_eq!((cell.glyph, cell.fg, cell.bg), ('>', BLUE, BLUE));
+        // revealing again keeps the first colour
+        assert_eq!(exploration.cell((0, 0), 1, &shown).fg, RED);
+    }
+
+    #[test]
+    fn hidden_layers_are_not_drawn() {
+        let exploration = exploration();
+        let cell = exploration.cell((0, 0), 1, &[false, true]);
+        assert_eq!((cell.glyph, cell.fg, cell.bg), ('.', WHITE, BLACK));
+        assert_eq!(exploration.cell((0, 1), 1, &[true, false]).bg, BLACK);
+    }
+
+    #[test]
+    fn cursor_follows_the_step() {
+        let exploration = exploration();
+        assert!(exploration.cell((0, 0), 0, &[false, false]).bold);
+        assert!(!exploration.cell((0, 0), 1, &[false, false]).bold);
+        assert_eq!(exploration.steps()[1].note, "move");
+    }
+
+    #[test]
+    fn every_day_explores_its_example() {
+        for &day in DAYS {
+            let example = input::example(day).unwrap();
+            let exploration = explore(day, &example, &Palette::default())
+                .unwrap()
+                .unwrap();
+            assert!(exploration.steps().len() > 1, "day {day}");
+            assert!(!exploration.layers().is_empty(), "day {day}");
+        }
+        assert!(explore(4, "XMAS\n", &Palette::default()).is_none());
+    }
+
+    #[test]
+    fn looping_guards_end_the_exploration() {
+        let exploration = explore(6, ".#.\n#^#\n.#.\n", &Palette::default())
+            .unwrap()
+            .unwrap();
+        let last = exploration.steps().last().unwrap();
+        assert_eq!(
+            last.note,
+            "the guard walks in a loop after visiting 1 cells"
+        );
+    }
+}
@@ -0,0 +1,284 @@
+//! Downloading puzzle inputs into the input directory.
+//!
+//! Inputs are fetched once with the session cookie of a logged in account and
+//! then only ever read from the cache, so the puzzle server sees at most one
+//! request per day. The base URL is configurable so the client can be pointed
+//! at a local stand-in server in tests.
+
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::input;
+
+/// Environment variable holding the `session` cookie of adventofcode.com.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// Environment variable overriding [`DEFAULT_BASE_URL`].
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const YEAR: u16 = 2024;
+
+/// Sent with every request, as the puzzle author asks of automated tools.
+const USER_AGENT: &str = concat!(
+    "advent-of-code-2024 input fetcher ",
+    env!("CARGO_PKG_VERSION")
+);
+
+#[derive(Debug)]
+pub enum FetchError {
+    /// No session token was configured.
+    NoSession,
+    /// The server answered with something other than 200 OK.
+    Status { day: u8, status: u16 },
+    /// The request itself failed.
+    Http { day: u8, message: String },
+    /// The input could not be written to the cache.
+    Io { path: PathBuf, source: io::Error },
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::NoSession => write!(
+                f,
+                "no session token, set {SESSION_VAR} to the session cookie of adventofcode.com"
+            ),
+            FetchError::Status { day, status } => {
+                write!(f, "fetching day {day} failed with HTTP status {status}")?;
+                match status {
+                    400 | 500 => write!(f, " (is the session token still valid?)"),
+                    404 => write!(f, " (is the puzzle unlocked yet?)"),
+                    _ => Ok(()),
+                }
+            }
+            FetchError::Http { day, message } => write!(f, "fetching day {day} failed: {message}"),
+            FetchError::Io { path, source } => {
+                write!(f, "unable to write {}: {source}", path.display())
+            }
+        }
+    }
+}
+
+impl Error for FetchError {}
+
+/// Something that can download the input of a day.
+pub trait Fetch {
+    fn fetch(&self, day: u8) -> Result<String, FetchError>;
+}
+
+/// Fetches inputs over HTTP from the puzzle server at `base_url`.
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: impl Into<String>, session: impl Into<String>) -> Self {
+        let agent = ureq::Agent::config_builder()
+            .user_agent(USER_AGENT)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build()
+            .into();
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_owned(),
+            session: session.into(),
+            agent,
+        }
+    }
+
+    /// A client configured from [`SESSION_VAR`] and [`BASE_URL_VAR`].
+    pub fn from_env() -> Result<Self, FetchError> {
+        let session = std::env::var(SESSION_VAR)
+            .ok()
+            .filter(|session| !session.trim().is_empty())
+            .ok_or(FetchError::NoSession)?;
+        let base_url = std::env::var(BASE_URL_VAR).unwrap_or_else(|_| DEFAULT_BASE_URL.to_owned());
+        Ok(Self::new(base_url, session.trim()))
+    }
+
+    pub fn url(&self, day: u8) -> String {
+        format!("{}/{YEAR}/day/{day}/input", self.base_url)
+    }
+}
+
+impl Fetch for Client {
+    fn fetch(&self, day: u8) -> Result<String, FetchError> {
+        let http = |e: ureq::Error| match e {
+            ureq::Error::StatusCode(status) => FetchError::Status { day, status },
+            e => FetchError::Http {
+                day,
+                message: e.to_string(),
+            },
+        };
+        self.agent
+            .get(&self.url(day))
+            .header("Cookie", &format!("session={}", self.session))
+            .call()
+            .map_err(http)?
+            .body_mut()
+            .read_to_string()
+            .map_err(http)
+    }
+}
+
+/// Whether the input at `path` has been fetched already. An empty file is
+/// only a placeholder, so it is fetched over.
+pub fn is_cached(path: &Path) -> bool {
+    fs::metadata(path).is_ok_and(|metadata| metadata.len() > 0)
+}
+
+/// Returns the path of the input for `day` in `dir`, fetching it with
+/// `fetcher` only if it is not cached there yet.
+pub fn cached(dir: &Path, day: u8, fetcher: &impl Fetch) -> Result<PathBuf, FetchError> {
+    let path = input::path_in(dir, day);
+    if is_cached(&path) {
+        return Ok(path);
+    }
+    let contents = fetcher.fetch(day)?;
+    let io = |source| FetchError::Io {
+        path: path.clone(),
+        source,
+    };
+    fs::create_dir_all(dir).map_err(io)?;
+    // write to a temporary file first so an interrupted download is not
+    // mistaken for a cached input next time
+    let partial = path.with_extension("txt.partial");
+    fs::write(&partial, contents).map_err(io)?;
+    fs::rename(&partial, &path).map_err(io)?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc;
+    use std::thread;
+
+    /// Answers `requests` HTTP requests on a local port with `status` and
+    /// `body`, reporting each request line and cookie back.
+    fn stand_in(
+        status: u16,
+        body: &'static str,
+        requests: usize,
+    ) -> (String, mpsc::Receiver<(String, String)>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, received) = mpsc::channel();
+        thread::spawn(move || {
+            for stream in listener.incoming().take(requests) {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                let mut cookie = String::new();
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    if header.trim().is_empty() {
+                        break;
+                    }
+                    if let Some((name, value)) = header.split_once(':') {
+                        if name.eq_ignore_ascii_case("cookie") {
+                            cookie = value.trim().to_owned();
+                        }
+                    }
+                }
+                let response = format!(
+                    "HTTP/1.1 {status} Whatever\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                stream.write_all(response.as_bytes()).unwrap();
+                sender
+                    .send((request_line.trim().to_owned(), cookie))
+                    .unwrap();
+            }
+        });
+        (base_url, received)
+    }
+
+    /// A fresh, empty cache directory.
+    fn cache_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-fetch-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    struct Counting {
+        calls: Cell<usize>,
+    }
+
+    impl Fetch for Counting {
+        fn fetch(&self, day: u8) -> Result<String, FetchError> {
+            self.calls.set(self.calls.get() + 1);
+            Ok(format!("input of day {day}\n"))
+        }
+    }
+
+    #[test]
+    fn client_sends_session_and_reads_input() {
+        let (base_url, requests) = stand_in(200, "3   4\n4   3\n", 1);
+        let client = Client::new(base_url + "/", "abc123");
+        assert_eq!(client.fetch(1).unwrap(), "3   4\n4   3\n");
+        let (request_line, cookie) = requests.recv().unwrap();
+        assert_eq!(request_line, "GET /2024/day/1/input HTTP/1.1");
+        assert_eq!(cookie, "session=abc123");
+    }
+
+    #[test]
+    fn client_reports_http_status() {
+        let (base_url, _requests) = stand_in(404, "Please don't repeatedly request this", 1);
+        let e = Client::new(base_url, "abc123").fetch(25).unwrap_err();
+        assert!(
+            matches!(
+                e,
+                FetchError::Status {
+                    day: 25,
+                    status: 404
+                }
+            ),
+            "{e}"
+        );
+    }
+
+    #[test]
+    fn cached_inputs_are_never_fetched_again() {
+        let dir = cache_dir("cached");
+        let fetcher = Counting {
+            calls: Cell::new(0),
+        };
+        let path = cached(&dir, 6, &fetcher).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "input of day 6\n");
+        assert_eq!(cached(&dir, 6, &fetcher).unwrap(), path);
+        assert_eq!(fetcher.calls.get(), 1);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn empty_placeholders_are_fetched_over() {
+        let dir = cache_dir("placeholder");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(input::path_in(&dir, 13), "").unwrap();
+        let fetcher = Counting {
+            calls: Cell::new(0),
+        };
+        let path = cached(&dir, 13, &fetcher).unwrap();
+        assert_eq!(fetcher.calls.get(), 1);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "input of day 13\n");
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn failed_fetches_leave_nothing_behind() {
+        let dir = cache_dir("failed");
+        let (base_url, _requests) = stand_in(500, "", 1);
+        assert!(cached(&dir, 2, &Client::new(base_url, "expired")).is_err());
+        assert!(!input::path_in(&dir, 2).exists());
+    }
+}
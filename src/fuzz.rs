@@ -0,0 +1,118 @@
+//! Fuzzing of every parser and solver on arbitrary text.
+//!
+//! Inputs are either random strings over the characters puzzle inputs are
+//! made of, or the worked examples with random edits applied, which keeps
+//! them close enough to valid to get past the first line. The property is
+//! the same for all of them: parsing returns an error or an input, and a
+//! parsed input solves or reports an overflow, without panicking. Tests build
+//! with overflow checks, so unchecked arithmetic shows up as a panic too.
+//!
+//! Set `PROPTEST_CASES` to fuzz for longer, e.g.
+//! `PROPTEST_CASES=100000 cargo test fuzz`. Stick to the debug profile, the
+//! release one turns overflow checks off.
+
+use proptest::prelude::*;
+
+use crate::input;
+use crate::solution::{self, Part, Solver};
+
+/// Every character that appears in some day's input, plus a few that do not.
+const ALPHABET: &[char] = &[
+    '0', '1', '2', '5', '9', ' ', ' ', ',', '|', ':', '\n', '\n', '-', '#', '.', '^', 'X', 'M',
+    'A', 'S', 'm', 'u', 'l', '(', ')', 'd', 'o', 'n', '\'', 't', 'é', '\t', '\r',
+];
+
+/// Numbers at the edges of the integer types the days parse into.
+const NUMBERS: &[&str] = &[
+    "2147483647",
+    "4294967295",
+    "4294967296",
+    "9223372036854775807",
+    "18446744073709551615",
+    "18446744073709551616",
+    "99999999999999",
+];
+
+/// The worked example of `day`, unless it has none yet, as for a day just
+/// scaffolded with `aoc new`.
+fn example(day: u8) -> Option<String> {
+    input::example(day)
+        .ok()
+        .filter(|example| !example.trim().is_empty())
+}
+
+fn text() -> impl Strategy<Value = String> {
+    let token = prop_oneof![
+        8 => prop::sample::select(ALPHABET).prop_map(String::from),
+        1 => prop::sample::select(NUMBERS).prop_map(String::from),
+    ];
+    prop::collection::vec(token, 0..200).prop_map(|tokens| tokens.concat())
+}
+
+#[derive(Debug, Clone)]
+enum Edit {
+    Insert(char),
+    InsertNumber(&'static str),
+    Delete,
+    Replace(char),
+}
+
+fn edit() -> impl Strategy<Value = (prop::sample::Index, Edit)> {
+    let c = || prop::sample::select(ALPHABET);
+    let edit = prop_oneof![
+        c().prop_map(Edit::Insert),
+        prop::sample::select(NUMBERS).prop_map(Edit::InsertNumber),
+        Just(Edit::Delete),
+        c().prop_map(Edit::Replace),
+    ];
+    (any::<prop::sample::Index>(), edit)
+}
+
+/// A day's example with a few random characters inserted, deleted or
+/// replaced.
+fn mutated_example() -> impl Strategy<Value = (Solver, String)> {
+    let examples: Vec<(Solver, Vec<char>)> = solution::SOLVERS
+        .iter()
+        .filter_map(|&solver| Some((solver, example(solver.day)?.chars().collect())))
+        .collect();
+    let examples = prop::sample::select(examples);
+    (examples, prop::collection::vec(edit(), 1..6)).prop_map(|((solver, mut chars), edits)| {
+        for (at, edit) in edits {
+            let i = at.index(chars.len() + 1);
+            match edit {
+                Edit::Insert(c) => chars.insert(i, c),
+                Edit::InsertNumber(n) => {
+                    chars.splice(i..i, n.chars());
+                }
+                Edit::Delete if i < chars.len() => {
+                    chars.remove(i);
+                }
+                Edit::Replace(c) if i < chars.len() => chars[i] = c,
+                _ => {}
+            }
+        }
+        (solver, chars.into_iter().collect())
+    })
+}
+
+/// Parses `input` and, if it parses, solves both parts.
+fn check(solver: &Solver, input: &str) {
+    let _ = solver.solve(input, &Part::ALL);
+}
+
+proptest! {
+    #[test]
+    fn fuzz_random_text(solver in prop::sample::select(solution::SOLVERS), input in text()) {
+        check(&solver, &input);
+    }
+
+    #[test]
+    fn fuzz_arbitrary_unicode(solver in prop::sample::select(solution::SOLVERS), input in ".*") {
+        check(&solver, &input);
+    }
+
+    #[test]
+    fn fuzz_mutated_examples((solver, input) in mutated_example()) {
+        check(&solver, &input);
+    }
+}
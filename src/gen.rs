@@ -0,0 +1,444 @@
+//! Deterministic synthetic puzzle inputs for scale testing the solvers.
+//!
+//! Each generator produces text the day's parser accepts, shaped like the real
+//! puzzle input. The same seed and size always give the same input, on every
+//! platform, since the random numbers come from a fixed in-tree generator.
+
+use std::fmt::Write;
+
+/// Knobs shared by every generator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Params {
+    pub seed: u64,
+    /// How big the input is; see [`default_size`] for what it counts per day.
+    pub size: usize,
+}
+
+/// The size of the real puzzle input for `day`: the number of lines for list
+/// days, the side of the grid for grid days, the number of instructions for
+/// day 3, updates for day 5, digits for day 9 and stones for day 11.
+pub fn default_size(day: u8) -> Option<usize> {
+    let size = match day {
+        1 | 2 => 1000,
+        3 => 700,
+        4 | 12 => 140,
+        5 => 200,
+        6 => 130,
+        7 => 850,
+        8 | 10 => 50,
+        9 => 19_999,
+        11 => 8,
+        _ => return None,
+    };
+    Some(size)
+}
+
+/// Generates an input for `day`, or `None` if there is no generator for it.
+pub fn generate(day: u8, params: Params) -> Option<String> {
+    let mut rng = Rng::new(params.seed);
+    let size = params.size.max(1);
+    let text = match day {
+        1 => location_lists(&mut rng, size),
+        2 => level_reports(&mut rng, size),
+        3 => corrupted_memory(&mut rng, size),
+        4 => word_search(&mut rng, size),
+        5 => page_rules(&mut rng, size),
+        6 => guard_map(&mut rng, size),
+        7 => calibrations(&mut rng, size),
+        8 => antenna_map(&mut rng, size),
+        9 => disk_map(&mut rng, size),
+        10 => topographic_map(&mut rng, size),
+        11 => stones(&mut rng, size),
+        12 => garden(&mut rng, size),
+        _ => return None,
+    };
+    Some(text)
+}
+
+/// A small SplitMix64 generator, so inputs do not change with a dependency
+/// upgrade.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `low..=high`.
+    pub fn between(&mut self, low: u64, high: u64) -> u64 {
+        low + self.next_u64() % (high - low + 1)
+    }
+
+    /// An index below `n`, which must not be zero.
+    pub fn index(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    /// True with probability `percent` in 100.
+    pub fn chance(&mut self, percent: u64) -> bool {
+        self.next_u64() % 100 < percent
+    }
+
+    pub fn pick<T: Copy>(&mut self, items: &[T]) -> T {
+        items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+}
+
+fn location_lists(rng: &mut Rng, lines: usize) -> String {
+    let left: Vec<u64> = (0..lines).map(|_| rng.between(10_000, 99_999)).collect();
+    let mut out = String::new();
+    for &l in &left {
+        // Reuse left values now and then so the similarity score is not zero.
+        let r = if rng.chance(30) {
+            rng.pick(&left)
+        } else {
+            rng.between(10_000, 99_999)
+        };
+        let _ = writeln!(out, "{l}   {r}");
+    }
+    out
+}
+
+fn level_reports(rng: &mut Rng, reports: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..reports {
+        let len = rng.between(5, 8);
+        let increasing = rng.chance(50);
+        let mut level = rng.between(20, 70) as i64;
+        let mut levels = vec![level];
+        for _ in 1..len {
+            let mut step = rng.between(1, 3) as i64;
+            // An occasional bad step, which the dampener may or may not fix.
+            if rng.chance(8) {
+                step = rng.pick(&[0, 4, 5, -2]);
+            }
+            level += if increasing { step } else { -step };
+            levels.push(level);
+        }
+        let line: Vec<String> = levels.iter().map(i64::to_string).collect();
+        let _ = writeln!(out, "{}", line.join(" "));
+    }
+    out
+}
+
+fn corrupted_memory(rng: &mut Rng, instructions: usize) -> String {
+    const JUNK: &[&str] = &[
+        "", "", "", "%", "!", "@", "#", "*", "&", "^", "]", "[", "<", ">", "?", "'", ")", "(", " ",
+        ",", "~", "$", "+", "{", "}", "mul", "what()", "select()", "from()", "how()", "where()",
+        "who()", "do", "don't",
+    ];
+    let mut out = String::new();
+    for i in 0..instructions {
+        for _ in 0..rng.between(0, 4) {
+            out.push_str(rng.pick(JUNK));
+        }
+        let (a, b) = (rng.between(1, 999), rng.between(1, 999));
+        match rng.between(0, 19) {
+            0 | 1 => out.push_str("do()"),
+            2 | 3 => out.push_str("don't()"),
+            4 => {
+                let _ = write!(out, "mul({a} {b})");
+            }
+            5 => {
+                let _ = write!(out, "mul[{a},{b}]");
+            }
+            6 => {
+                let _ = write!(out, "mul({a},{b}!");
+            }
+            _ => {
+                let _ = write!(out, "mul({a},{b})");
+            }
+        }
+        if i % 120 == 119 {
+            out.push('\n');
+        }
+    }
+    out.push('\n');
+    out
+}
+
+fn word_search(rng: &mut Rng, side: usize) -> String {
+    grid(side, side, |_, _| rng.pick(&['X', 'M', 'A', 'S']))
+}
+
+fn page_rules(rng: &mut Rng, updates: usize) -> String {
+    // The real input orders every pair of 49 pages, so any update can be
+    // sorted by the rules alone.
+    let mut pages: Vec<u64> = (10..100).collect();
+    rng.shuffle(&mut pages);
+    pages.truncate(49);
+
+    let mut rules = Vec::new();
+    for (i, before) in pages.iter().enumerate() {
+        for after in &pages[i + 1..] {
+            rules.push((before, after));
+        }
+    }
+    rng.shuffle(&mut rules);
+
+    let mut out = String::new();
+    for (before, after) in rules {
+        let _ = writeln!(out, "{before}|{after}");
+    }
+    out.push('\n');
+
+    for _ in 0..updates {
+        let len = 2 * rng.between(2, 11) as usize + 1;
+        let mut chosen: Vec<usize> = (0..pages.len()).collect();
+        rng.shuffle(&mut chosen);
+        chosen.truncate(len);
+        if rng.chance(50) {
+            chosen.sort_unstable();
+        }
+        let update: Vec<String> = chosen.iter().map(|&i| pages[i].to_string()).collect();
+        let _ = writeln!(out, "{}", update.join(","));
+    }
+    out
+}
+
+fn guard_map(rng: &mut Rng, side: usize) -> String {
+    let side = side.max(2);
+    // Part 1 assumes the guard walks off the map, so reroll until it does.
+    loop {
+        let blocked: Vec<bool> = (0..side * side).map(|_| rng.chance(6)).collect();
+        let start = rng.index(side * side);
+        if blocked[start] || !guard_leaves(&blocked, side, start) {
+            continue;
+        }
+        return grid(side, side, |i, j| match i * side + j {
+            pos if pos == start => '^',
+            pos if blocked[pos] => '#',
+            _ => '.',
+        });
+    }
+}
+
+/// Walks a guard facing up from `start` and reports whether it leaves the map.
+fn guard_leaves(blocked: &[bool], side: usize, start: usize) -> bool {
+    const STEPS: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+    let mut seen = vec![[false; 4]; blocked.len()];
+    let (mut row, mut col, mut dir) = ((start / side) as isize, (start % side) as isize, 0);
+    loop {
+        let pos = row as usize * side + col as usize;
+        if std::mem::replace(&mut seen[pos][dir], true) {
+            return false;
+        }
+        let (next_row, next_col) = (row + STEPS[dir].0, col + STEPS[dir].1);
+        let side = side as isize;
+        if !(0..side).contains(&next_row) || !(0..side).contains(&next_col) {
+            return true;
+        }
+        if blocked[(next_row * side + next_col) as usize] {
+            dir = (dir + 1) % 4;
+        } else {
+            (row, col) = (next_row, next_col);
+        }
+    }
+}
+
+fn calibrations(rng: &mut Rng, lines: usize) -> String {
+    // Keeps targets well inside u64 however the operators fall.
+    const LIMIT: u64 = 100_000_000_000_000;
+    let mut out = String::new();
+    for _ in 0..lines {
+        let len = rng.between(2, 12);
+        let numbers: Vec<u64> = (0..len)
+            .map(|_| match rng.between(0, 3) {
+                0 => rng.between(100, 999),
+                1 => rng.between(10, 99),
+                _ => rng.between(1, 9),
+            })
+            .collect();
+        let mut target = numbers[0];
+        for &n in &numbers[1..] {
+            let concat = target * 10u64.pow(n.ilog10() + 1) + n;
+            target = match rng.between(0, 2) {
+                0 if concat < LIMIT => concat,
+                1 if target * n < LIMIT => target * n,
+                _ => target + n,
+            };
+        }
+        if rng.chance(40) {
+            target += rng.between(1, 100);
+        }
+        let numbers: Vec<String> = numbers.iter().map(u64::to_string).collect();
+        let _ = writeln!(out, "{target}: {}", numbers.join(" "));
+    }
+    out
+}
+
+fn antenna_map(rng: &mut Rng, side: usize) -> String {
+    const FREQUENCIES: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+    let mut cells = vec!['.'; side * side];
+    let count = (side * 7 / 10).clamp(1, FREQUENCIES.len());
+    for &frequency in &FREQUENCIES[..count] {
+        for _ in 0..rng.between(2, 4) {
+            let pos = rng.index(cells.len());
+            if cells[pos] == '.' {
+                cells[pos] = frequency as char;
+            }
+        }
+    }
+    grid(side, side, |i, j| cells[i * side + j])
+}
+
+fn disk_map(rng: &mut Rng, digits: usize) -> String {
+    let mut out: String = (0..digits)
+        .map(|i| {
+            let digit = if i % 2 == 0 {
+                rng.between(1, 9)
+            } else {
+                rng.between(0, 9)
+            };
+            char::from(b'0' + digit as u8)
+        })
+        .collect();
+    out.push('\n');
+    out
+}
+
+fn topographic_map(rng: &mut Rng, side: usize) -> String {
+    // Heights fall away from scattered summits, so most slopes hold a trail,
+    // with some noise so not every step is even.
+    let cells = side * side;
+    let mut distance = vec![usize::MAX; cells];
+    let mut queue = std::collections::VecDeque::new();
+    for _ in 0..(cells / 40).max(1) {
+        let summit = rng.index(cells);
+        distance[summit] = 0;
+        queue.push_back(summit);
+    }
+    while let Some(pos) = queue.pop_front() {
+        let (i, j) = (pos / side, pos % side);
+        let neighbours = [
+            (i > 0).then(|| pos - side),
+            (i + 1 < side).then(|| pos + side),
+            (j > 0).then(|| pos - 1),
+            (j + 1 < side).then(|| pos + 1),
+        ];
+        for next in neighbours.into_iter().flatten() {
+            if distance[next] == usize::MAX {
+                distance[next] = distance[pos] + 1;
+                queue.push_back(next);
+            }
+        }
+    }
+    grid(side, side, |i, j| {
+        let height = if rng.chance(10) {
+            rng.between(0, 9) as usize
+        } else {
+            9 - distance[i * side + j].min(9)
+        };
+        char::from(b'0' + height as u8)
+    })
+}
+
+fn stones(rng: &mut Rng, count: usize) -> String {
+    let stones: Vec<String> = (0..count)
+        .map(|_| match rng.between(0, 3) {
+            0 => rng.between(0, 9),
+            1 => rng.between(10, 9_999),
+            _ => rng.between(10_000, 9_999_999),
+        })
+        .map(|stone| stone.to_string())
+        .collect();
+    stones.join(" ") + "\n"
+}
+
+fn garden(rng: &mut Rng, side: usize) -> String {
+    // Copying a neighbouring plot most of the time grows regions.
+    let mut plots = vec!['A'; side * side];
+    for i in 0..side {
+        for j in 0..side {
+            let plot = if j > 0 && rng.chance(45) {
+                plots[i * side + j - 1]
+            } else if i > 0 && rng.chance(70) {
+                plots[(i - 1) * side + j]
+            } else {
+                char::from(b'A' + rng.between(0, 25) as u8)
+            };
+            plots[i * side + j] = plot;
+        }
+    }
+    grid(side, side, |i, j| plots[i * side + j])
+}
+
+/// Renders an `n` by `m` grid of characters, one row per line.
+fn grid(n: usize, m: usize, mut cell: impl FnMut(usize, usize) -> char) -> String {
+    let mut out = String::with_capacity(n * (m + 1));
+    for i in 0..n {
+        out.extend((0..m).map(|j| cell(i, j)));
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::{self, Part};
+
+    #[test]
+    fn same_seed_same_input() {
+        let params = Params { seed: 7, size: 20 };
+        for day in 1..=12 {
+            assert_eq!(generate(day, params), generate(day, params), "day {day}");
+        }
+        let other = Params { seed: 8, ..params };
+        assert_ne!(generate(3, params), generate(3, other));
+    }
+
+    #[test]
+    fn every_generator_has_a_solver() {
+        // days scaffolded with `aoc new` are solved before they get a
+        // generator, so only the other direction must hold
+        for day in 1..=25 {
+            if default_size(day).is_some() {
+                assert!(solution::solver(day).is_some(), "day {day}");
+            }
+        }
+        assert_eq!(generate(25, Params { seed: 0, size: 1 }), None);
+    }
+
+    #[test]
+    fn generated_inputs_solve() {
+        for seed in 0..3 {
+            for solver in solution::SOLVERS {
+                let params = Params { seed, size: 15 };
+                let Some(input) = generate(solver.day, params) else {
+                    continue;
+                };
+                let answers = solver.solve(&input, &Part::ALL);
+                assert!(
+                    answers.is_ok(),
+                    "day {} seed {seed}: {answers:?}",
+                    solver.day
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn page_updates_have_a_middle() {
+        let input = generate(5, Params { seed: 1, size: 50 }).unwrap();
+        let (_, updates) = input.split_once("\n\n").unwrap();
+        for update in updates.lines() {
+            assert_eq!(update.split(',').count() % 2, 1, "{update}");
+        }
+    }
+}
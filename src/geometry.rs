@@ -0,0 +1,204 @@
+//! Points and directions on a grid indexed by `(row, column)`.
+//!
+//! Rows grow downwards, so [`Direction::Up`] points towards row 0. Points are
+//! signed so that vector arithmetic can step off the grid before a bounds
+//! check, and convert back to `(usize, usize)` grid indices when they are on
+//! it.
+
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A signed `(row, column)` position or offset.
+#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Point {
+    pub row: i32,
+    pub col: i32,
+}
+
+impl Point {
+    pub const ZERO: Point = Point { row: 0, col: 0 };
+
+    pub const fn new(row: i32, col: i32) -> Self {
+        Self { row, col }
+    }
+
+    /// The grid index of this point, if neither coordinate is negative.
+    pub fn to_index(self) -> Option<(usize, usize)> {
+        let row = usize::try_from(self.row).ok()?;
+        let col = usize::try_from(self.col).ok()?;
+        Some((row, col))
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((row, col): (usize, usize)) -> Self {
+        Self::new(row as i32, col as i32)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.row + other.row, self.col + other.col)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.row - other.row, self.col - other.col)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<i32> for Point {
+    type Output = Point;
+
+    fn mul(self, k: i32) -> Point {
+        Point::new(self.row * k, self.col * k)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.row, -self.col)
+    }
+}
+
+/// One of the eight compass directions.
+#[derive(Default, Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum Direction {
+    #[default]
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction {
+    /// Every direction, clockwise from up.
+    pub const ALL: [Direction; 8] = [
+        Direction::Up,
+        Direction::UpRight,
+        Direction::Right,
+        Direction::DownRight,
+        Direction::Down,
+        Direction::DownLeft,
+        Direction::Left,
+        Direction::UpLeft,
+    ];
+
+    /// The four orthogonal directions, clockwise from up.
+    pub const ORTHOGONAL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// The four diagonal directions, clockwise from up and right.
+    pub const DIAGONAL: [Direction; 4] = [
+        Direction::UpRight,
+        Direction::DownRight,
+        Direction::DownLeft,
+        Direction::UpLeft,
+    ];
+
+    /// The offset of a single step in this direction.
+    pub fn offset(self) -> Point {
+        match self {
+            Direction::Up => Point::new(-1, 0),
+            Direction::UpRight => Point::new(-1, 1),
+            Direction::Right => Point::new(0, 1),
+            Direction::DownRight => Point::new(1, 1),
+            Direction::Down => Point::new(1, 0),
+            Direction::DownLeft => Point::new(1, -1),
+            Direction::Left => Point::new(0, -1),
+            Direction::UpLeft => Point::new(-1, -1),
+        }
+    }
+
+    /// The point one step from `point` in this direction.
+    pub fn forward(self, point: Point) -> Point {
+        point + self.offset()
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        let offset = self.offset();
+        offset.row != 0 && offset.col != 0
+    }
+
+    /// Turns 90 degrees clockwise.
+    pub fn clockwise(self) -> Self {
+        self.rotate(2)
+    }
+
+    /// Turns 90 degrees counter-clockwise.
+    pub fn counter_clockwise(self) -> Self {
+        self.rotate(6)
+    }
+
+    pub fn opposite(self) -> Self {
+        self.rotate(4)
+    }
+
+    /// Turns clockwise by `eighths` steps of 45 degrees.
+    pub fn rotate(self, eighths: usize) -> Self {
+        let index = Self::ALL.iter().position(|&d| d == self).unwrap();
+        Self::ALL[(index + eighths) % 8]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn point_arithmetic() {
+        let a = Point::new(4, 6);
+        let b = Point::new(5, 4);
+        assert_eq!(a + b, Point::new(9, 10));
+        assert_eq!(b - a, Point::new(1, -2));
+        assert_eq!((b - a) * 3, Point::new(3, -6));
+        assert_eq!(-a, Point::new(-4, -6));
+    }
+
+    #[test]
+    fn only_non_negative_points_are_indices() {
+        assert_eq!(Point::new(2, 0).to_index(), Some((2, 0)));
+        assert_eq!(Point::new(-1, 3).to_index(), None);
+        assert_eq!(Point::from((7, 8)), Point::new(7, 8));
+    }
+
+    #[test]
+    fn turning() {
+        assert_eq!(Direction::Up.clockwise(), Direction::Right);
+        assert_eq!(Direction::Left.clockwise(), Direction::Up);
+        assert_eq!(Direction::Up.counter_clockwise(), Direction::Left);
+        assert_eq!(Direction::UpRight.opposite(), Direction::DownLeft);
+        for d in Direction::ALL {
+            assert_eq!(d.offset() + d.opposite().offset(), Point::ZERO);
+            assert_eq!(d.clockwise().counter_clockwise(), d);
+        }
+        assert!(Direction::DIAGONAL.iter().all(|d| d.is_diagonal()));
+        assert!(!Direction::ORTHOGONAL.iter().any(|d| d.is_diagonal()));
+    }
+}
@@ -0,0 +1,311 @@
+//! Rectangular grids parsed from the puzzle text.
+
+use std::ops::{Index, IndexMut};
+
+use crate::error::{ParseError, Result};
+use crate::geometry::{Direction, Point};
+use crate::parse;
+
+/// A rectangular grid stored row-major in a single allocation.
+///
+/// Cells are addressed as `(row, column)`; `n` is the number of rows and `m`
+/// the number of columns, matching the naming used across the solutions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    n: usize,
+    m: usize,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from the lines of `text`, mapping every byte with `f`.
+    /// Bytes `f` rejects, rows of differing width and empty input are reported
+    /// as errors.
+    pub fn parse(text: &[u8], mut f: impl FnMut(u8) -> Option<T>) -> Result<Self> {
+        Self::from_rows(text, |cells, line| {
+            for (j, &b) in line.iter().enumerate() {
+                let Some(cell) = f(b) else {
+                    let text = parse::char_at(line, j);
+                    return Err(ParseError::in_line(line, text, "unexpected character"));
+                };
+                cells.push(cell);
+            }
+            Ok(())
+        })
+    }
+
+    /// Builds a grid with `row` appending the cells of each line of `text`,
+    /// checking every row is as wide as the first. Blank lines at the end of
+    /// `text` are not rows.
+    fn from_rows(
+        text: &[u8],
+        mut row: impl FnMut(&mut Vec<T>, &[u8]) -> Result<()>,
+    ) -> Result<Self> {
+        let mut cells = Vec::with_capacity(text.len());
+        let mut n = 0;
+        let mut m = 0;
+        for line in parse::lines(parse::trim_blank_lines(text)) {
+            let before = cells.len();
+            row(&mut cells, line).map_err(|e| e.on_line(n + 1))?;
+            let width = cells.len() - before;
+            if n == 0 {
+                m = width;
+            } else if width != m {
+                let message = format!("row has {width} columns, expected {m}");
+                return Err(ParseError::new(
+                    n + 1,
+                    1,
+                    &String::from_utf8_lossy(line),
+                    message,
+                ));
+            }
+            n += 1;
+        }
+        if n == 0 || m == 0 {
+            return Err(ParseError::at_end(text, "expected a grid"));
+        }
+        Ok(Self { cells, n, m })
+    }
+
+    pub fn n(&self) -> usize {
+        self.n
+    }
+
+    pub fn m(&self) -> usize {
+        self.m
+    }
+
+    pub fn get(&self, i: usize, j: usize) -> Option<&T> {
+        if i < self.n && j < self.m {
+            Some(&self.cells[i * self.m + j])
+        } else {
+            None
+        }
+    }
+
+    /// The grid index of `point`, if it is on the grid.
+    pub fn index_of(&self, point: Point) -> Option<(usize, usize)> {
+        let (i, j) = point.to_index()?;
+        (i < self.n && j < self.m).then_some((i, j))
+    }
+
+    /// Bounds check for signed points, which may have stepped off the grid.
+    pub fn contains(&self, point: Point) -> bool {
+        self.index_of(point).is_some()
+    }
+
+    pub fn get_point(&self, point: Point) -> Option<&T> {
+        let (i, j) = self.index_of(point)?;
+        self.get(i, j)
+    }
+
+    /// The position `offset` away from `pos`, if it is still on the grid.
+    pub fn offset(&self, pos: (usize, usize), offset: Point) -> Option<(usize, usize)> {
+        self.index_of(Point::from(pos) + offset)
+    }
+
+    /// The position one step away from `(i, j)`, if it is still on the grid.
+    pub fn step(&self, pos: (usize, usize), direction: Direction) -> Option<(usize, usize)> {
+        self.offset(pos, direction.offset())
+    }
+
+    /// The orthogonal neighbours of `pos` that are on the grid.
+    pub fn neighbours4(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        Direction::ORTHOGONAL
+            .into_iter()
+            .filter_map(move |d| self.step(pos, d))
+    }
+
+    /// The orthogonal and diagonal neighbours of `pos` that are on the grid.
+    pub fn neighbours8(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |d| self.step(pos, d))
+    }
+
+    pub fn row(&self, i: usize) -> &[T] {
+        &self.cells[i * self.m..(i + 1) * self.m]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.m)
+    }
+
+    pub fn column(&self, j: usize) -> impl Iterator<Item = &T> {
+        assert!(j < self.m, "column {j} is outside the grid");
+        self.cells[j..].iter().step_by(self.m)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.m).map(move |j| self.column(j))
+    }
+
+    /// Every diagonal running down and to the right, starting from the top
+    /// right corner and ending at the bottom left one.
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let starts = (0..self.m)
+            .rev()
+            .map(|j| (0, j))
+            .chain((1..self.n).map(|i| (i, 0)));
+        starts.map(move |(i, j)| (0..).map_while(move |k| self.get(i + k, j + k)))
+    }
+
+    /// Every diagonal running down and to the left, starting from the top left
+    /// corner and ending at the bottom right one.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let starts = (0..self.m)
+            .map(|j| (0, j))
+            .chain((1..self.n).map(|i| (i, self.m - 1)));
+        starts.map(move |(i, j)| (0..=j).map_while(move |k| self.get(i + k, j - k)))
+    }
+
+    /// Every `(row, column)` position in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let m = self.m;
+        (0..self.n).flat_map(move |i| (0..m).map(move |j| (i, j)))
+    }
+
+    /// The position of the first cell matching `predicate`, in row-major order.
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<(usize, usize)> {
+        let k = self.cells.iter().position(predicate)?;
+        Some((k / self.m, k % self.m))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            n: self.n,
+            m: self.m,
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// The grid mirrored along its main diagonal, so rows become columns.
+    pub fn transpose(&self) -> Self {
+        Self {
+            cells: self.columns().flatten().cloned().collect(),
+            n: self.m,
+            m: self.n,
+        }
+    }
+}
+
+impl Grid<u8> {
+    /// The bytes of `text` as a grid, which must be ASCII so every cell is a
+    /// single byte.
+    pub fn from_bytes(text: &[u8]) -> Result<Self> {
+        Self::from_rows(text, |cells, line| {
+            if let Some(j) = line.iter().position(|b| !b.is_ascii()) {
+                let text = parse::char_at(line, j);
+                return Err(ParseError::in_line(line, text, "unexpected character"));
+            }
+            cells.extend_from_slice(line);
+            Ok(())
+        })
+    }
+}
+
+impl Grid<char> {
+    /// The characters of `text` as a grid, which must be ASCII.
+    pub fn from_chars(text: &str) -> Result<Self> {
+        Self::parse(text.as_bytes(), |b| b.is_ascii().then_some(b as char))
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (i, j): (usize, usize)) -> &T {
+        assert!(i < self.n && j < self.m, "({i}, {j}) is outside the grid");
+        &self.cells[i * self.m + j]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut T {
+        assert!(i < self.n && j < self.m, "({i}, {j}) is outside the grid");
+        &mut self.cells[i * self.m + j]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_is_row_major() {
+        let grid = Grid::from_chars("ab\ncd\nef\n").unwrap();
+        assert_eq!((grid.n(), grid.m()), (3, 2));
+        assert_eq!(grid[(1, 0)], 'c');
+        assert_eq!(grid.get(2, 1), Some(&'f'));
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.position(|&c| c == 'd'), Some((1, 1)));
+    }
+
+    #[test]
+    fn step_stays_on_grid() {
+        let grid = Grid::from_chars("..\n..\n").unwrap();
+        assert_eq!(grid.step((0, 0), Direction::Up), None);
+        assert_eq!(grid.step((0, 0), Direction::Right), Some((0, 1)));
+        assert_eq!(grid.step((1, 1), Direction::Down), None);
+    }
+
+    #[test]
+    fn points_off_the_grid() {
+        let grid = Grid::from_chars("ab\ncd\n").unwrap();
+        assert_eq!(grid.get_point(Point::new(1, 0)), Some(&'c'));
+        assert!(!grid.contains(Point::new(-1, 0)));
+        assert!(!grid.contains(Point::new(0, 2)));
+        assert_eq!(grid.offset((1, 1), Point::new(-1, -1)), Some((0, 0)));
+    }
+
+    #[test]
+    fn neighbours_are_clipped_to_the_grid() {
+        let grid = Grid::from_chars("abc\ndef\n").unwrap();
+        let corner: Vec<_> = grid.neighbours4((0, 0)).collect();
+        assert_eq!(corner, vec![(0, 1), (1, 0)]);
+        let middle: Vec<_> = grid.neighbours8((0, 1)).collect();
+        assert_eq!(middle, vec![(0, 2), (1, 2), (1, 1), (1, 0), (0, 0)]);
+    }
+
+    #[test]
+    fn line_iterators() {
+        let grid = Grid::from_chars("abc\ndef\n").unwrap();
+        let collect = |line: &mut dyn Iterator<Item = &char>| line.collect::<String>();
+
+        assert_eq!(grid.row(1), &['d', 'e', 'f']);
+        assert_eq!(grid.rows().count(), 2);
+        let columns: Vec<_> = grid.columns().map(|mut c| collect(&mut c)).collect();
+        assert_eq!(columns, vec!["ad", "be", "cf"]);
+        let diagonals: Vec<_> = grid.diagonals().map(|mut d| collect(&mut d)).collect();
+        assert_eq!(diagonals, vec!["c", "bf", "ae", "d"]);
+        let anti: Vec<_> = grid.anti_diagonals().map(|mut d| collect(&mut d)).collect();
+        assert_eq!(anti, vec!["a", "bd", "ce", "f"]);
+    }
+
+    #[test]
+    fn transpose_swaps_rows_and_columns() {
+        let grid = Grid::from_chars("abc\ndef\n").unwrap();
+        let transposed = grid.transpose();
+        assert_eq!((transposed.n(), transposed.m()), (3, 2));
+        assert_eq!(transposed, Grid::from_chars("ad\nbe\ncf\n").unwrap());
+        assert_eq!(transposed.transpose(), grid);
+    }
+
+    #[test]
+    fn malformed_grids_are_errors() {
+        let e = Grid::parse(b"12\n3x\n", |b| b.is_ascii_digit().then(|| b - b'0')).unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 2, "x"));
+
+        let e = Grid::from_chars("ab\ncç\n").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 2, "ç"));
+        let e = Grid::from_bytes("ab\néc\n".as_bytes()).unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 1, "é"));
+
+        let e = Grid::from_chars("abc\nab\n").unwrap_err();
+        assert_eq!(e.line, 2);
+
+        assert!(Grid::from_chars("").is_err());
+    }
+}
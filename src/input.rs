@@ -0,0 +1,111 @@
+//! Locating and loading puzzle inputs.
+//!
+//! By default inputs are read from `inputs/dayXX.txt` next to `Cargo.toml`, so
+//! the solutions work no matter which directory they are run from. Setting
+//! `AOC_INPUT_DIR` points the lookup at another directory instead, and a
+//! solver can always be handed an explicit file or `-` for stdin.
+
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Environment variable overriding the directory puzzle inputs are read from.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// The directory holding the `dayXX.txt` inputs.
+pub fn dir() -> PathBuf {
+    match std::env::var_os(INPUT_DIR_VAR) {
+        Some(dir) => PathBuf::from(dir),
+        None => Path::new(env!("CARGO_MANIFEST_DIR")).join("inputs"),
+    }
+}
+
+/// Path of the puzzle input for `day` inside `dir`, e.g. `inputs/day06.txt`.
+pub fn path_in(dir: &Path, day: u8) -> PathBuf {
+    dir.join(format!("day{day:02}.txt"))
+}
+
+/// Path of the puzzle input for `day` in the configured input directory.
+pub fn path(day: u8) -> PathBuf {
+    path_in(&dir(), day)
+}
+
+/// The worked example of `day` checked into `inputs/examples/dayXX.txt`.
+pub fn example(day: u8) -> io::Result<String> {
+    let examples = Path::new(env!("CARGO_MANIFEST_DIR")).join("inputs/examples");
+    fs::read_to_string(path_in(&examples, day))
+}
+
+/// Where a solver reads its input from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// The day's file in the input directory.
+    Day(u8),
+    /// An explicit file.
+    Path(PathBuf),
+    /// Standard input, selected with `-` on the command line.
+    Stdin,
+}
+
+impl Source {
+    /// Interprets an optional command line argument for `day`.
+    pub fn from_arg(day: u8, arg: Option<&str>) -> Self {
+        match arg {
+            None => Source::Day(day),
+            Some("-") => Source::Stdin,
+            Some(path) => Source::Path(PathBuf::from(path)),
+        }
+    }
+
+    /// The file this source reads, if any.
+    pub fn path(&self) -> Option<PathBuf> {
+        match self {
+            Source::Day(day) => Some(path(*day)),
+            Source::Path(path) => Some(path.clone()),
+            Source::Stdin => None,
+        }
+    }
+
+    pub fn read(&self) -> io::Result<String> {
+        match self.path() {
+            Some(path) => fs::read_to_string(path),
+            None => {
+                let mut contents = String::new();
+                io::stdin().read_to_string(&mut contents)?;
+                Ok(contents)
+            }
+        }
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.path() {
+            Some(path) => write!(f, "{}", path.display()),
+            None => write!(f, "<stdin>"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn day_paths_are_zero_padded() {
+        let dir = Path::new("somewhere");
+        assert_eq!(path_in(dir, 6), Path::new("somewhere/day06.txt"));
+        assert_eq!(path_in(dir, 12), Path::new("somewhere/day12.txt"));
+    }
+
+    #[test]
+    fn dash_means_stdin() {
+        assert_eq!(Source::from_arg(3, None), Source::Day(3));
+        assert_eq!(Source::from_arg(3, Some("-")), Source::Stdin);
+        assert_eq!(
+            Source::from_arg(3, Some("alt.txt")),
+            Source::Path(PathBuf::from("alt.txt"))
+        );
+    }
+}
@@ -0,0 +1,21 @@
+//! Shared infrastructure for the Advent of Code 2024 solutions.
+//!
+//! Every day pulls its input loading, parsing helpers, grid storage and
+//! geometry from here so fixes and optimizations only need to land once.
+
+pub mod answers;
+pub mod bench;
+pub mod days;
+pub mod error;
+pub mod explore;
+pub mod fetch;
+#[cfg(test)]
+mod fuzz;
+pub mod gen;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod parse;
+pub mod record;
+pub mod render;
+pub mod solution;
@@ -0,0 +1,297 @@
+//! Parsing helpers shared by the day solutions, working directly on the bytes
+//! of the input.
+//!
+//! The input is read once and every day parses it as a `&[u8]`: lines,
+//! separated fields and grid rows are borrowed slices of it, and integers are
+//! accumulated digit by digit, so parsing never allocates a `String` per line
+//! or per token. Every helper reports failures as a [`ParseError`] pointing at
+//! the text it could not understand.
+
+use crate::error::{ParseError, Result};
+
+/// Integer types that can be parsed from ASCII decimal digits.
+pub trait Integer: Copy {
+    /// Parses `token`, an optional sign followed by decimal digits, with the
+    /// same rules and errors as [`str::parse`].
+    fn from_digits(token: &[u8]) -> std::result::Result<Self, &'static str>;
+}
+
+macro_rules! integer {
+    ($($t:ty),*) => {$(
+        impl Integer for $t {
+            fn from_digits(token: &[u8]) -> std::result::Result<Self, &'static str> {
+                let (negative, digits) = match token {
+                    [] => return Err("cannot parse integer from empty string"),
+                    [b'+', rest @ ..] => (false, rest),
+                    #[allow(unused_comparisons)]
+                    [b'-', rest @ ..] if <$t>::MIN < 0 => (true, rest),
+                    _ => (false, token),
+                };
+                if digits.is_empty() {
+                    return Err("invalid digit found in string");
+                }
+                let mut value: $t = 0;
+                for &b in digits {
+                    let digit = b.wrapping_sub(b'0');
+                    if digit > 9 {
+                        return Err("invalid digit found in string");
+                    }
+                    // accumulating towards the sign lets the minimum value
+                    // parse without overflowing on the way
+                    let shifted = value.checked_mul(10);
+                    value = if negative {
+                        shifted.and_then(|v| v.checked_sub(digit as $t))
+                            .ok_or("number too small to fit in target type")?
+                    } else {
+                        shifted.and_then(|v| v.checked_add(digit as $t))
+                            .ok_or("number too large to fit in target type")?
+                    };
+                }
+                Ok(value)
+            }
+        }
+    )*};
+}
+
+integer!(u8, u16, u32, u64, usize, i32, i64);
+
+/// The lines of `input`, without their `\n` or `\r\n`, like [`str::lines`].
+pub fn lines(input: &[u8]) -> Lines<'_> {
+    Lines { rest: input }
+}
+
+#[derive(Debug, Clone)]
+pub struct Lines<'a> {
+    rest: &'a [u8],
+}
+
+impl<'a> Iterator for Lines<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<&'a [u8]> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        let (line, rest) = match find(self.rest, b'\n') {
+            Some(end) => (&self.rest[..end], &self.rest[end + 1..]),
+            None => (self.rest, &self.rest[self.rest.len()..]),
+        };
+        self.rest = rest;
+        Some(line.strip_suffix(b"\r").unwrap_or(line))
+    }
+}
+
+/// `input` without the blank lines at its end. Every parser ignores those, so
+/// an input saved with an extra line break parses the same as without it.
+pub fn trim_blank_lines(input: &[u8]) -> &[u8] {
+    let end = input
+        .iter()
+        .rposition(|&b| b != b'\n' && b != b'\r')
+        .map_or(0, |last| last + 1);
+    &input[..end]
+}
+
+/// Parses each line of `input` with `f`, tagging errors with their line.
+/// Blank lines at the end are skipped, and an input without any other line
+/// is an error.
+pub fn each_line<'a, T>(
+    input: &'a [u8],
+    mut f: impl FnMut(&'a [u8]) -> Result<T>,
+) -> Result<Vec<T>> {
+    let content = trim_blank_lines(input);
+    if content.is_empty() {
+        return Err(ParseError::at_end(input, "expected at least one line"));
+    }
+    lines(content)
+        .enumerate()
+        .map(|(i, line)| f(line).map_err(|e| e.on_line(i + 1)))
+        .collect()
+}
+
+/// The index of the first `needle` in `haystack`.
+///
+/// Compares eight bytes at a time, which matters for inputs like day 3's whose
+/// lines run to thousands of bytes.
+pub fn find(haystack: &[u8], needle: u8) -> Option<usize> {
+    const LOW: u64 = u64::from_ne_bytes([0x01; 8]);
+    const HIGH: u64 = u64::from_ne_bytes([0x80; 8]);
+    let repeated = LOW * u64::from(needle);
+    let mut chunks = haystack.chunks_exact(8);
+    let mut start = 0;
+    for chunk in chunks.by_ref() {
+        // a byte of `x` is zero where the chunk holds the needle, and only
+        // then can its high bit survive this expression
+        let x = u64::from_ne_bytes(chunk.try_into().unwrap()) ^ repeated;
+        if x.wrapping_sub(LOW) & !x & HIGH != 0 {
+            break;
+        }
+        start += 8;
+    }
+    let found = haystack[start..].iter().position(|&b| b == needle)?;
+    Some(start + found)
+}
+
+/// Splits `bytes` around the first `sep`.
+pub fn split_once(bytes: &[u8], sep: u8) -> Option<(&[u8], &[u8])> {
+    let at = find(bytes, sep)?;
+    Some((&bytes[..at], &bytes[at + 1..]))
+}
+
+/// Parses `part`, a slice of `line`, as a `T`.
+pub fn integer<T: Integer>(line: &[u8], part: &[u8]) -> Result<T> {
+    T::from_digits(part)
+        .map_err(|e| ParseError::in_line(line, part, format!("invalid number ({e})")))
+}
+
+/// Parses every `sep` separated part of `line` as a `T`, skipping the empty
+/// parts left by repeated separators.
+pub fn integers<T: Integer>(line: &[u8], sep: u8) -> Result<Vec<T>> {
+    line.trim_ascii()
+        .split(|&b| b == sep)
+        .filter(|part| !part.is_empty())
+        .map(|part| integer(line, part))
+        .collect()
+}
+
+/// Splits `line` once on `sep` and parses both halves as a `T`.
+pub fn pair<T: Integer>(line: &[u8], sep: u8) -> Result<(T, T)> {
+    let Some((a, b)) = split_once(line, sep) else {
+        return Err(ParseError::in_line(
+            line,
+            line,
+            format!("expected two values separated by {:?}", sep as char),
+        ));
+    };
+    Ok((
+        integer(line, a.trim_ascii())?,
+        integer(line, b.trim_ascii())?,
+    ))
+}
+
+/// Converts every byte of `line` into its decimal digit.
+pub fn digits(line: &[u8]) -> Result<Vec<u8>> {
+    line.iter()
+        .enumerate()
+        .map(|(j, &b)| {
+            let digit = b.wrapping_sub(b'0');
+            if digit <= 9 {
+                Ok(digit)
+            } else {
+                Err(ParseError::in_line(
+                    line,
+                    char_at(line, j),
+                    "expected a digit",
+                ))
+            }
+        })
+        .collect()
+}
+
+/// The whole UTF-8 encoded character starting at byte `j` of `line`, so
+/// errors about a non-ASCII character quote all of it.
+pub fn char_at(line: &[u8], j: usize) -> &[u8] {
+    let width = match line[j] {
+        0xF0.. => 4,
+        0xE0.. => 3,
+        0xC0.. => 2,
+        _ => 1,
+    };
+    &line[j..(j + width).min(line.len())]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integers_match_str_parse() {
+        let tokens = [
+            "0",
+            "42",
+            "+7",
+            "-7",
+            "",
+            "-",
+            "+",
+            "4x",
+            "255",
+            "256",
+            "-2147483649",
+        ];
+        for token in tokens {
+            let bytes = token.as_bytes();
+            let errors = |e: &dyn std::fmt::Display| e.to_string();
+            assert_eq!(
+                u8::from_digits(bytes).map_err(|e| errors(&e)),
+                token.parse::<u8>().map_err(|e| errors(&e)),
+                "{token}"
+            );
+            assert_eq!(
+                i32::from_digits(bytes).map_err(|e| errors(&e)),
+                token.parse::<i32>().map_err(|e| errors(&e)),
+                "{token}"
+            );
+        }
+        assert_eq!(i64::from_digits(b"-9223372036854775808"), Ok(i64::MIN));
+        assert_eq!(
+            u64::from_digits(b"18446744073709551616"),
+            Err("number too large to fit in target type")
+        );
+    }
+
+    #[test]
+    fn find_checks_every_byte() {
+        let haystack: Vec<u8> = (0..40).collect();
+        for needle in 0..40 {
+            assert_eq!(find(&haystack, needle), Some(needle as usize));
+        }
+        assert_eq!(find(&haystack, 40), None);
+        assert_eq!(find(b"", b'\n'), None);
+        // bytes just above the needle must not look like it
+        assert_eq!(find(&[0x81, 0x0b, 0x0a], 0x0a), Some(2));
+    }
+
+    #[test]
+    fn lines_drop_line_endings() {
+        let lines: Vec<_> = lines(b"a\r\nb\n\nc").collect();
+        assert_eq!(lines, [&b"a"[..], b"b", b"", b"c"]);
+        assert_eq!(super::lines(b"a\n").count(), 1);
+    }
+
+    #[test]
+    fn each_line_skips_trailing_blank_lines_only() {
+        let parsed = each_line(b"1\n2\n\r\n\n", |line| integer::<u32>(line, line));
+        assert_eq!(parsed, Ok(vec![1, 2]));
+        let e = each_line(b"1\n\n2\n", |line| integer::<u32>(line, line)).unwrap_err();
+        assert_eq!(e.line, 2);
+
+        for empty in [&b""[..], b"\n", b"\n\n"] {
+            let e = each_line(empty, Ok).unwrap_err();
+            assert_eq!(e.message, "expected at least one line");
+        }
+    }
+
+    #[test]
+    fn integers_skips_repeated_separators() {
+        assert_eq!(integers::<i64>(b"3   4", b' '), Ok(vec![3, 4]));
+        assert_eq!(integers::<u32>(b"75,47,61\n", b','), Ok(vec![75, 47, 61]));
+    }
+
+    #[test]
+    fn pair_parses_both_halves() {
+        assert_eq!(pair::<u32>(b"47|53", b'|'), Ok((47, 53)));
+        assert_eq!(pair::<i64>(b"3   4", b' '), Ok((3, 4)));
+        assert!(pair::<u32>(b"47", b'|').is_err());
+    }
+
+    #[test]
+    fn errors_point_at_the_bad_token() {
+        let e = each_line(b"1 2\n3 x4\n", |line| integers::<u32>(line, b' ')).unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 3, "x4"));
+
+        let e = digits(b"12a4").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (1, 3, "a"));
+        let e = digits("1é".as_bytes()).unwrap_err();
+        assert_eq!((e.column, e.text.as_str()), (2, "é"));
+    }
+}
@@ -0,0 +1,369 @@
+//! Recording the intermediate states of the simulation days as frames.
+//!
+//! Days hand a [`Recorder`] a closure drawing their current state after every
+//! step; the recorder only calls it for the steps it keeps, so recording every
+//! hundredth move of a long simulation costs little more than solving it. Kept
+//! frames go straight to a [`Sink`], which saves them as an animated GIF or
+//! as numbered image files without holding the whole recording in memory.
+
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use crate::days::*;
+use crate::render::{Palette, Picture, Rgb};
+use crate::solution::{Part, SolveError};
+
+/// The simulations that can be recorded, as `(day, part)`.
+pub const SIMULATIONS: &[(u8, Part)] = &[
+    (6, Part::One),
+    (9, Part::One),
+    (9, Part::Two),
+    (11, Part::One),
+];
+
+/// Where a [`Recorder`] sends the frames it keeps, as soon as each is drawn.
+pub trait Sink {
+    fn frame(&mut self, picture: Picture) -> io::Result<()>;
+}
+
+/// Keeps every frame in memory.
+impl Sink for Vec<Picture> {
+    fn frame(&mut self, picture: Picture) -> io::Result<()> {
+        self.push(picture);
+        Ok(())
+    }
+}
+
+/// Passes a frame every `every` steps of a simulation to a [`Sink`], up to
+/// `limit` frames plus the final state.
+///
+/// A sink that fails ends the recording: later frames are no longer drawn and
+/// the error is returned by [`result`](Recorder::result).
+pub struct Recorder<'a> {
+    every: usize,
+    limit: usize,
+    steps: usize,
+    frames: usize,
+    last_kept: bool,
+    sink: &'a mut dyn Sink,
+    error: Option<io::Error>,
+}
+
+impl<'a> Recorder<'a> {
+    pub fn new(every: usize, limit: usize, sink: &'a mut dyn Sink) -> Self {
+        Self {
+            every: every.max(1),
+            limit,
+            steps: 0,
+            frames: 0,
+            last_kept: false,
+            sink,
+            error: None,
+        }
+    }
+
+    /// Counts one step of the simulation, drawing it with `draw` if it is one
+    /// of the steps kept.
+    pub fn step(&mut self, draw: impl FnOnce() -> Picture) {
+        self.last_kept = self.steps.is_multiple_of(self.every) && self.frames < self.limit;
+        if self.last_kept {
+            self.keep(draw);
+        }
+        self.steps += 1;
+    }
+
+    /// Ends the recording with the final state, unless the last step was
+    /// already kept.
+    pub fn finish(&mut self, draw: impl FnOnce() -> Picture) {
+        if !self.last_kept {
+            self.keep(draw);
+            self.last_kept = true;
+        }
+    }
+
+    fn keep(&mut self, draw: impl FnOnce() -> Picture) {
+        if self.error.is_none() {
+            if let Err(e) = self.sink.frame(draw()) {
+                self.error = Some(e);
+            }
+        }
+        self.frames += 1;
+    }
+
+    /// How many steps the simulation took.
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    /// How many frames were kept.
+    pub fn frames(&self) -> usize {
+        self.frames
+    }
+
+    /// The error the sink failed with, if it did.
+    pub fn result(&mut self) -> io::Result<()> {
+        self.error.take().map_or(Ok(()), Err)
+    }
+}
+
+/// Runs the simulation of `part` of `day` on `input`, recording it with
+/// `recorder`, or returns `None` if there is no such simulation.
+pub fn record(
+    day: u8,
+    part: Part,
+    input: &str,
+    palette: &Palette,
+    recorder: &mut Recorder,
+) -> Option<Result<(), SolveError>> {
+    if !SIMULATIONS.contains(&(day, part)) {
+        return None;
+    }
+    let recorded = match day {
+        6 => day06::record(input, palette, recorder).map_err(SolveError::from),
+        9 => day09::record(input, part, palette, recorder).map_err(SolveError::from),
+        _ => day11::record(input, palette, recorder),
+    };
+    Some(recorded)
+}
+
+/// Writes frames to an animated GIF looping forever, showing each frame for
+/// `delay` hundredths of a second.
+///
+/// Every frame is encoded as it arrives. Palettes only have a handful of
+/// colours, so frames are usually stored as indices into a colour table of
+/// their own instead of being quantized.
+pub struct GifWriter {
+    path: PathBuf,
+    scale: usize,
+    delay: u16,
+    encoder: Option<gif::Encoder<BufWriter<File>>>,
+    /// The size in cells of the first frame, which every frame must match.
+    size: (usize, usize),
+}
+
+impl GifWriter {
+    /// A writer creating `path` once the first frame arrives.
+    pub fn new(path: &Path, scale: usize, delay: u16) -> Self {
+        Self {
+            path: path.to_owned(),
+            scale,
+            delay,
+            encoder: None,
+            size: (0, 0),
+        }
+    }
+
+    /// Creates the file and starts a GIF of `width` by `height` pixels.
+    fn create(&self, width: usize, height: usize) -> io::Result<gif::Encoder<BufWriter<File>>> {
+        let (Ok(width), Ok(height)) = (u16::try_from(width), u16::try_from(height)) else {
+            return Err(invalid(
+                "a GIF is at most 65535 pixels wide and high, use a smaller --scale",
+            ));
+        };
+        let file = BufWriter::new(File::create(&self.path)?);
+        let mut encoder = gif::Encoder::new(file, width, height, &[]).map_err(io::Error::other)?;
+        encoder
+            .set_repeat(gif::Repeat::Infinite)
+            .map_err(io::Error::other)?;
+        Ok(encoder)
+    }
+
+    /// Ends the GIF, failing if no frame was written.
+    pub fn finish(self) -> io::Result<()> {
+        let Some(encoder) = self.encoder else {
+            return Err(invalid("there are no frames to save"));
+        };
+        encoder.into_inner()?.flush()
+    }
+}
+
+impl Sink for GifWriter {
+    fn frame(&mut self, picture: Picture) -> io::Result<()> {
+        let (width, height, pixels) = picture.pixels(self.scale);
+        if self.encoder.is_none() {
+            self.encoder = Some(self.create(width, height)?);
+            self.size = picture.size();
+        }
+        let Some(encoder) = &mut self.encoder else {
+            unreachable!("the encoder was just created");
+        };
+        if picture.size() != self.size {
+            return Err(invalid("every frame of a GIF must be the same size"));
+        }
+
+        let (width, height) = (width as u16, height as u16);
+        let mut table: HashMap<Rgb, u8> = HashMap::new();
+        let indices: Option<Vec<u8>> = pixels
+            .iter()
+            .map(|&pixel| {
+                let next = table.len();
+                match table.get(&pixel) {
+                    Some(&k) => Some(k),
+                    None if next < 256 => {
+                        table.insert(pixel, next as u8);
+                        Some(next as u8)
+                    }
+                    None => None,
+                }
+            })
+            .collect();
+        let mut frame = match indices {
+            Some(indices) => {
+                let mut colours = vec![0; 3 * table.len()];
+                for (&Rgb(r, g, b), &k) in &table {
+                    colours[3 * k as usize..3 * k as usize + 3].copy_from_slice(&[r, g, b]);
+                }
+                let mut frame = gif::Frame::from_indexed_pixels(width, height, indices, None);
+                frame.palette = Some(colours);
+                frame
+            }
+            None => {
+                let rgb: Vec<u8> = pixels.iter().flat_map(|p| [p.0, p.1, p.2]).collect();
+                gif::Frame::from_rgb_speed(width, height, &rgb, 10)
+            }
+        };
+        frame.delay = self.delay;
+        encoder.write_frame(&frame).map_err(io::Error::other)
+    }
+}
+
+/// Writes every frame into a directory as `frame00000.png` and so on.
+pub struct FrameDir {
+    dir: PathBuf,
+    scale: usize,
+    written: usize,
+}
+
+impl FrameDir {
+    /// Creates `dir` if it does not exist yet.
+    pub fn create(dir: &Path, scale: usize) -> io::Result<Self> {
+        fs::create_dir_all(dir)?;
+        Ok(Self {
+            dir: dir.to_owned(),
+            scale,
+            written: 0,
+        })
+    }
+}
+
+impl Sink for FrameDir {
+    fn frame(&mut self, picture: Picture) -> io::Result<()> {
+        let path = self.dir.join(format!("frame{:05}.png", self.written));
+        picture.save(&path, self.scale)?;
+        self.written += 1;
+        Ok(())
+    }
+}
+
+fn invalid(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message.to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input;
+
+    fn frame(k: u8) -> Picture {
+        Picture::new(2, 3, Rgb(k, k, k))
+    }
+
+    /// A temporary path that does not exist yet.
+    fn scratch(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("aoc-record-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&path);
+        let _ = fs::remove_file(&path);
+        path
+    }
+
+    #[test]
+    fn recorder_keeps_every_nth_step_and_the_last() {
+        let mut frames = Vec::new();
+        let mut recorder = Recorder::new(3, 100, &mut frames);
+        for k in 0..8 {
+            recorder.step(|| frame(k));
+        }
+        recorder.finish(|| frame(7));
+        assert_eq!((recorder.steps(), recorder.frames()), (8, 4));
+        let kept: Vec<Rgb> = frames.iter().map(|f| f.pixels(1).2[0]).collect();
+        assert_eq!(kept, [0, 3, 6, 7].map(|k| Rgb(k, k, k)));
+    }
+
+    #[test]
+    fn recorder_stops_at_the_limit() {
+        let mut frames = Vec::new();
+        let mut recorder = Recorder::new(1, 2, &mut frames);
+        for k in 0..5 {
+            recorder.step(|| frame(k));
+        }
+        recorder.finish(|| frame(4));
+        assert_eq!(frames.len(), 3);
+    }
+
+    #[test]
+    fn gif_has_every_frame() {
+        let path = scratch("frames.gif");
+        let mut gif = GifWriter::new(&path, 2, 5);
+        for k in [0, 100, 200] {
+            gif.frame(frame(k)).unwrap();
+        }
+        gif.finish().unwrap();
+        let mut options = gif::DecodeOptions::new();
+        options.set_color_output(gif::ColorOutput::RGBA);
+        let mut decoder = options.read_info(File::open(&path).unwrap()).unwrap();
+        assert_eq!((decoder.width(), decoder.height()), (6, 4));
+        let mut shades = Vec::new();
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            assert_eq!(frame.delay, 5);
+            shades.push(frame.buffer[0]);
+        }
+        assert_eq!(shades, [0, 100, 200]);
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn gif_frames_must_match_in_size() {
+        let path = scratch("mismatch.gif");
+        let mut gif = GifWriter::new(&path, 1, 5);
+        gif.frame(frame(0)).unwrap();
+        assert!(gif.frame(Picture::new(3, 3, Rgb(0, 0, 0))).is_err());
+        fs::remove_file(path).unwrap();
+        assert!(GifWriter::new(&scratch("empty.gif"), 1, 5)
+            .finish()
+            .is_err());
+    }
+
+    #[test]
+    fn frames_are_numbered() {
+        let dir = scratch("numbered");
+        let mut frames = FrameDir::create(&dir, 1).unwrap();
+        frames.frame(frame(0)).unwrap();
+        frames.frame(frame(1)).unwrap();
+        let mut names: Vec<_> = fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .collect();
+        names.sort();
+        assert_eq!(names, ["frame00000.png", "frame00001.png"]);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn every_simulation_records_its_example() {
+        for &(day, part) in SIMULATIONS {
+            let example = input::example(day).unwrap();
+            let mut frames = Vec::new();
+            let mut recorder = Recorder::new(1, 1000, &mut frames);
+            record(day, part, &example, &Palette::default(), &mut recorder)
+                .unwrap()
+                .unwrap();
+            assert!(frames.len() > 2, "day {day} part {part}");
+            assert!(frames.iter().all(|f| f.size() == frames[0].size()));
+        }
+        let mut frames = Vec::new();
+        let mut recorder = Recorder::new(1, 1, &mut frames);
+        assert!(record(4, Part::One, "XMAS\n", &Palette::default(), &mut recorder).is_none());
+    }
+}
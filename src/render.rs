@@ -0,0 +1,465 @@
+//! Drawing grids, and what the solvers found on them, as images.
+//!
+//! A [`Picture`] holds one colour per cell plus overlays marking sets of cells
+//! (a guard's path, antinodes, trails, ...). It is drawn as a binary PPM or a
+//! PNG with every cell `scale` pixels wide, or as an SVG with one rectangle
+//! per cell.
+
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::days::*;
+use crate::error::ParseError;
+use crate::geometry::Point;
+use crate::grid::Grid;
+
+/// The days whose input is a grid worth drawing.
+pub const DAYS: &[u8] = &[4, 6, 8, 10, 12];
+
+/// Draws the input of `day` along with what its solver found, or `None` if the
+/// day has no renderer.
+pub fn render(day: u8, input: &str, palette: &Palette) -> Option<Result<Picture, ParseError>> {
+    let picture = match day {
+        4 => day04::render(input, palette),
+        6 => day06::render(input, palette),
+        8 => day08::render(input, palette),
+        10 => day10::render(input, palette),
+        12 => day12::render(input, palette),
+        _ => return None,
+    };
+    Some(picture)
+}
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    /// Parses `#rrggbb`, with or without the `#`.
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let hex = s.trim().trim_start_matches('#');
+        let channel = |i: usize| {
+            hex.get(i..i + 2)
+                .and_then(|c| u8::from_str_radix(c, 16).ok())
+                .ok_or_else(|| format!("colour must look like #rrggbb, found {s:?}"))
+        };
+        if hex.len() != 6 {
+            return Err(format!("colour must look like #rrggbb, found {s:?}"));
+        }
+        Ok(Rgb(channel(0)?, channel(2)?, channel(4)?))
+    }
+
+    /// The colour `t` of the way from `self` to `other`.
+    pub fn mix(self, other: Rgb, t: f64) -> Rgb {
+        let t = t.clamp(0.0, 1.0);
+        let channel = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+        Rgb(
+            channel(self.0, other.0),
+            channel(self.1, other.1),
+            channel(self.2, other.2),
+        )
+    }
+
+    pub fn hex(self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+
+/// The colours a picture is drawn with.
+///
+/// Days draw empty cells in `background` and walls or other fixed features in
+/// `foreground`, and pick `accents` for what they found, cycling through them
+/// when there are more categories (regions, frequencies) than colours.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Palette {
+    pub background: Rgb,
+    pub foreground: Rgb,
+    pub accents: Vec<Rgb>,
+}
+
+impl Palette {
+    /// Parses a comma separated list of colours: background, foreground and
+    /// then at least one accent.
+    pub fn parse(list: &str) -> Result<Self, String> {
+        let colours = list
+            .split(',')
+            .map(Rgb::parse)
+            .collect::<Result<Vec<_>, _>>()?;
+        let [background, foreground, accents @ ..] = colours.as_slice() else {
+            return Err("a palette needs a background, a foreground and an accent".to_owned());
+        };
+        if accents.is_empty() {
+            return Err("a palette needs a background, a foreground and an accent".to_owned());
+        }
+        Ok(Self {
+            background: *background,
+            foreground: *foreground,
+            accents: accents.to_vec(),
+        })
+    }
+
+    /// The `i`th accent, wrapping around.
+    pub fn accent(&self, i: usize) -> Rgb {
+        self.accents[i % self.accents.len()]
+    }
+
+    /// A colour `t` of the way along the accents, from the first to the last.
+    pub fn gradient(&self, t: f64) -> Rgb {
+        let t = t.clamp(0.0, 1.0) * (self.accents.len() - 1) as f64;
+        let i = (t.floor() as usize).min(self.accents.len().saturating_sub(2));
+        match self.accents.get(i + 1) {
+            Some(&next) => self.accents[i].mix(next, t - i as f64),
+            None => self.accents[i],
+        }
+    }
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Self {
+            background: Rgb(0x0f, 0x0f, 0x23),
+            foreground: Rgb(0xcc, 0xcc, 0xcc),
+            accents: vec![
+                Rgb(0xff, 0xff, 0x66),
+                Rgb(0x00, 0x99, 0x00),
+                Rgb(0xe6, 0x4a, 0x19),
+                Rgb(0x42, 0x87, 0xf5),
+                Rgb(0xd1, 0x5c, 0xc9),
+                Rgb(0x2e, 0xc4, 0xb6),
+                Rgb(0xff, 0x9f, 0x1c),
+                Rgb(0x9b, 0x5d, 0xe5),
+            ],
+        }
+    }
+}
+
+/// How an overlay marks its cells.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mark {
+    /// Paints over the whole cell.
+    Fill,
+    /// A square in the middle of the cell, leaving its colour visible around.
+    Dot,
+    /// A border around the cell.
+    Outline,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Overlay {
+    pub cells: Vec<Point>,
+    pub color: Rgb,
+    pub mark: Mark,
+}
+
+/// The file formats a picture can be saved as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Ppm,
+    Png,
+    Svg,
+}
+
+impl Format {
+    /// The format matching the extension of `path`.
+    pub fn of(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+        match extension.as_str() {
+            "ppm" => Some(Format::Ppm),
+            "png" => Some(Format::Png),
+            "svg" => Some(Format::Svg),
+            _ => None,
+        }
+    }
+}
+
+/// A coloured grid with overlays, ready to be drawn.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Picture {
+    n: usize,
+    m: usize,
+    cells: Vec<Rgb>,
+    overlays: Vec<Overlay>,
+}
+
+impl Picture {
+    /// An `n` by `m` picture with every cell in `background`.
+    pub fn new(n: usize, m: usize, background: Rgb) -> Self {
+        Self {
+            n,
+            m,
+            cells: vec![background; n * m],
+            overlays: Vec::new(),
+        }
+    }
+
+    /// Colours the cell at `(row, column)`, ignoring cells outside the picture.
+    pub fn paint(&mut self, (i, j): (usize, usize), color: Rgb) {
+        if i < self.n && j < self.m {
+            self.cells[i * self.m + j] = color;
+        }
+    }
+
+    /// The size of the picture in cells, as `(rows, columns)`.
+    pub fn size(&self) -> (usize, usize) {
+        (self.n, self.m)
+    }
+
+    /// Colours every cell of `grid` with `color`.
+    pub fn from_grid<T>(grid: &Grid<T>, mut color: impl FnMut(&T) -> Rgb) -> Self {
+        let cells = grid.positions().map(|pos| color(&grid[pos])).collect();
+        Self {
+            n: grid.n(),
+            m: grid.m(),
+            cells,
+            overlays: Vec::new(),
+        }
+    }
+
+    /// Marks `cells` on top of what is drawn so far. Cells outside the grid
+    /// are ignored.
+    pub fn overlay(
+        &mut self,
+        cells: impl IntoIterator<Item = Point>,
+        color: Rgb,
+        mark: Mark,
+    ) -> &mut Self {
+        let cells = cells
+            .into_iter()
+            .filter(|p| p.to_index().is_some_and(|(i, j)| i < self.n && j < self.m))
+            .collect();
+        self.overlays.push(Overlay { cells, color, mark });
+        self
+    }
+
+    pub fn overlays(&self) -> &[Overlay] {
+        &self.overlays
+    }
+
+    /// The picture as pixels, row-major, `scale` pixels per cell side.
+    pub fn pixels(&self, scale: usize) -> (usize, usize, Vec<Rgb>) {
+        let scale = scale.max(1);
+        let (width, height) = (self.m * scale, self.n * scale);
+        let mut pixels = vec![Rgb(0, 0, 0); width * height];
+        let mut paint = |i: usize, j: usize, color: Rgb, inside: &dyn Fn(usize, usize) -> bool| {
+            for y in 0..scale {
+                for x in 0..scale {
+                    if inside(y, x) {
+                        pixels[(i * scale + y) * width + j * scale + x] = color;
+                    }
+                }
+            }
+        };
+
+        for (k, &color) in self.cells.iter().enumerate() {
+            paint(k / self.m, k % self.m, color, &|_, _| true);
+        }
+        let (dot_from, dot_to) = (scale / 4, scale - scale / 4);
+        let border = (scale / 8).max(1);
+        for overlay in &self.overlays {
+            let inside: &dyn Fn(usize, usize) -> bool = match overlay.mark {
+                Mark::Fill => &|_, _| true,
+                Mark::Dot if scale < 3 => &|_, _| true,
+                Mark::Dot => {
+                    &|y, x| (dot_from..dot_to).contains(&y) && (dot_from..dot_to).contains(&x)
+                }
+                Mark::Outline => {
+                    &|y, x| y < border || x < border || y >= scale - border || x >= scale - border
+                }
+            };
+            for cell in &overlay.cells {
+                let (i, j) = (cell.row as usize, cell.col as usize);
+                paint(i, j, overlay.color, inside);
+            }
+        }
+        (width, height, pixels)
+    }
+
+    /// A binary (P6) PPM image.
+    pub fn to_ppm(&self, scale: usize) -> Vec<u8> {
+        let (width, height, pixels) = self.pixels(scale);
+        let mut out = format!("P6\n{width} {height}\n255\n").into_bytes();
+        out.extend(pixels.iter().flat_map(|p| [p.0, p.1, p.2]));
+        out
+    }
+
+    pub fn to_png(&self, scale: usize) -> Vec<u8> {
+        let (width, height, pixels) = self.pixels(scale);
+        let data: Vec<u8> = pixels.iter().flat_map(|p| [p.0, p.1, p.2]).collect();
+        let mut out = Vec::new();
+        let mut encoder = png::Encoder::new(&mut out, width as u32, height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        // writing to memory only fails on invalid dimensions, which a grid
+        // never has
+        let mut writer = encoder.write_header().expect("valid png header");
+        writer.write_image_data(&data).expect("valid png data");
+        drop(writer);
+        out
+    }
+
+    pub fn to_svg(&self, scale: usize) -> String {
+        let s = scale.max(1);
+        let (width, height) = (self.m * s, self.n * s);
+        let mut out = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+             viewBox=\"0 0 {width} {height}\" shape-rendering=\"crispEdges\">\n"
+        );
+        for (k, color) in self.cells.iter().enumerate() {
+            let (y, x) = (k / self.m * s, k % self.m * s);
+            let _ = writeln!(
+                out,
+                "<rect x=\"{x}\" y=\"{y}\" width=\"{s}\" height=\"{s}\" fill=\"{}\"/>",
+                color.hex()
+            );
+        }
+        for overlay in &self.overlays {
+            let color = overlay.color.hex();
+            let _ = writeln!(out, "<g class=\"overlay\">");
+            for cell in &overlay.cells {
+                let (y, x) = (cell.row as f64 * s as f64, cell.col as f64 * s as f64);
+                let s = s as f64;
+                let _ = match overlay.mark {
+                    Mark::Fill => writeln!(
+                        out,
+                        "<rect x=\"{x}\" y=\"{y}\" width=\"{s}\" height=\"{s}\" fill=\"{color}\"/>"
+                    ),
+                    Mark::Dot => writeln!(
+                        out,
+                        "<circle cx=\"{}\" cy=\"{}\" r=\"{}\" fill=\"{color}\"/>",
+                        x + s / 2.0,
+                        y + s / 2.0,
+                        s / 4.0
+                    ),
+                    Mark::Outline => writeln!(
+                        out,
+                        "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"none\" \
+                         stroke=\"{color}\" stroke-width=\"{}\"/>",
+                        x + s / 16.0,
+                        y + s / 16.0,
+                        s * 7.0 / 8.0,
+                        s * 7.0 / 8.0,
+                        s / 8.0
+                    ),
+                };
+            }
+            let _ = writeln!(out, "</g>");
+        }
+        out.push_str("</svg>\n");
+        out
+    }
+
+    /// Writes the picture to `path` in the format its extension names.
+    pub fn save(&self, path: &Path, scale: usize) -> io::Result<()> {
+        let contents = match Format::of(path) {
+            Some(Format::Ppm) => self.to_ppm(scale),
+            Some(Format::Png) => self.to_png(scale),
+            Some(Format::Svg) => self.to_svg(scale).into_bytes(),
+            None => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "the file name must end in .ppm, .png or .svg",
+                ))
+            }
+        };
+        fs::write(path, contents)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input;
+
+    const RED: Rgb = Rgb(255, 0, 0);
+    const BLUE: Rgb = Rgb(0, 0, 255);
+
+    fn picture() -> Picture {
+        let grid = Grid::from_chars("#.\n..\n").unwrap();
+        let mut picture = Picture::from_grid(&grid, |&c| if c == '#' { RED } else { BLUE });
+        picture.overlay(
+            [Point::new(1, 1), Point::new(5, 5)],
+            Rgb(0, 255, 0),
+            Mark::Fill,
+        );
+        picture
+    }
+
+    #[test]
+    fn colours_parse_from_hex() {
+        assert_eq!(Rgb::parse("#ff8000"), Ok(Rgb(255, 128, 0)));
+        assert_eq!(Rgb::parse("0a0B0c"), Ok(Rgb(10, 11, 12)));
+        assert!(Rgb::parse("#fff").is_err());
+        assert!(Rgb::parse("#gg0000").is_err());
+    }
+
+    #[test]
+    fn palettes_need_an_accent() {
+        let palette = Palette::parse("#000000,#ffffff,#ff0000,#0000ff").unwrap();
+        assert_eq!(palette.accent(3), BLUE);
+        assert_eq!(palette.gradient(0.0), RED);
+        assert_eq!(palette.gradient(1.0), BLUE);
+        assert!(Palette::parse("#000000,#ffffff").is_err());
+    }
+
+    #[test]
+    fn overlays_outside_the_grid_are_dropped() {
+        assert_eq!(picture().overlays()[0].cells, vec![Point::new(1, 1)]);
+    }
+
+    #[test]
+    fn ppm_has_a_pixel_per_scaled_cell() {
+        let ppm = picture().to_ppm(2);
+        let header = b"P6\n4 4\n255\n";
+        assert_eq!(&ppm[..header.len()], header);
+        let pixels = &ppm[header.len()..];
+        assert_eq!(pixels.len(), 4 * 4 * 3);
+        assert_eq!(&pixels[..3], &[255, 0, 0]);
+        // bottom right cell is covered by the overlay
+        assert_eq!(&pixels[pixels.len() - 3..], &[0, 255, 0]);
+    }
+
+    #[test]
+    fn png_round_trips() {
+        let png = picture().to_png(3);
+        let decoder = png::Decoder::new(png.as_slice());
+        let mut reader = decoder.read_info().unwrap();
+        let mut data = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut data).unwrap();
+        assert_eq!((info.width, info.height), (6, 6));
+        assert_eq!(&data[..3], &[255, 0, 0]);
+    }
+
+    #[test]
+    fn svg_draws_every_cell_and_mark() {
+        let svg = picture().to_svg(10);
+        assert!(svg.starts_with("<svg"));
+        assert_eq!(svg.matches("<rect").count(), 4 + 1);
+        assert!(svg.contains("fill=\"#00ff00\""));
+    }
+
+    #[test]
+    fn every_grid_day_renders_its_example() {
+        for &day in DAYS {
+            let example = input::example(day).unwrap();
+            let picture = render(day, &example, &Palette::default()).unwrap().unwrap();
+            assert!(picture.to_svg(4).ends_with("</svg>\n"));
+        }
+        assert!(render(1, "3   4\n", &Palette::default()).is_none());
+    }
+
+    #[test]
+    fn looping_guards_render_without_hanging() {
+        let picture = render(6, ".#.\n#^#\n.#.\n", &Palette::default())
+            .unwrap()
+            .unwrap();
+        assert_eq!(picture.size(), (3, 3));
+    }
+
+    #[test]
+    fn format_follows_the_extension() {
+        assert_eq!(Format::of(Path::new("out/day06.PNG")), Some(Format::Png));
+        assert_eq!(Format::of(Path::new("day06.svg")), Some(Format::Svg));
+        assert_eq!(Format::of(Path::new("day06.gif")), None);
+    }
+}
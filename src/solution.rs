@@ -0,0 +1,354 @@
+//! The common shape of every day's solver and the registry the runner uses.
+
+use std::fmt::Display;
+use std::hint::black_box;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use crate::days::*;
+use crate::error::{Checked, Overflow, ParseError};
+
+/// A puzzle solution split into parsing and the two parts.
+///
+/// Parsing happens once and both parts share the parsed input, so neither part
+/// pays for reading the text again. The parsed input may borrow from the text.
+pub trait Solution {
+    /// The day of December this solution belongs to.
+    const DAY: u8;
+
+    type Input<'a>;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+    fn part1(input: &Self::Input<'_>) -> Checked<Self::Answer1>;
+    fn part2(input: &Self::Input<'_>) -> Checked<Self::Answer2>;
+
+    /// Explains `part` item by item, for days whose answer adds up what each
+    /// item of the input contributes. The contributions sum to the answer.
+    fn explain(_input: &Self::Input<'_>, _part: Part) -> Option<Vec<Decision>> {
+        None
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("part must be 1 or 2, found {s:?}")),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// The answer to one part of a puzzle, rendered as text so answers of every
+/// day share a type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    pub part: Part,
+    pub value: String,
+}
+
+impl Answer {
+    pub fn new(part: Part, value: impl Display) -> Self {
+        Self {
+            part,
+            value: value.to_string(),
+        }
+    }
+}
+
+/// What one item of the input, a report, an instruction, an update, ...,
+/// contributed to an answer and why.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Decision {
+    pub item: String,
+    pub verdict: String,
+    pub contribution: u64,
+}
+
+impl Decision {
+    pub fn new(item: impl Display, verdict: impl Into<String>, contribution: u64) -> Self {
+        Self {
+            item: item.to_string(),
+            verdict: verdict.into(),
+            contribution,
+        }
+    }
+}
+
+/// Why a solver could not produce its answers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    Parse(ParseError),
+    Overflow { part: Part, overflow: Overflow },
+}
+
+impl SolveError {
+    /// Names the file the input came from, for parse errors.
+    pub fn in_file(self, file: impl Into<String>) -> Self {
+        match self {
+            SolveError::Parse(e) => SolveError::Parse(e.in_file(file)),
+            e => e,
+        }
+    }
+}
+
+impl From<ParseError> for SolveError {
+    fn from(e: ParseError) -> Self {
+        SolveError::Parse(e)
+    }
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SolveError::Parse(e) => write!(f, "{e}"),
+            SolveError::Overflow { part, overflow } => write!(f, "part {part}: {overflow}"),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+/// The result of solving some parts of a day once, with how long each step
+/// took.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Run {
+    pub parse: Duration,
+    pub parts: Vec<PartRun>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartRun {
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+/// A type erased handle on a [`Solution`] so days can be picked at runtime.
+#[derive(Debug, Clone, Copy)]
+pub struct Solver {
+    pub day: u8,
+    parse: fn(&str) -> Result<(), ParseError>,
+    run: fn(&str, &[Part]) -> Result<Run, SolveError>,
+    time: fn(&str) -> Result<Timing, SolveError>,
+    explain: fn(&str, Part) -> Result<Explanation, ParseError>,
+}
+
+/// The decisions behind an answer, if the day can give them.
+type Explanation = Option<Vec<Decision>>;
+
+/// How long each phase of a single run took.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timing {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+impl Solver {
+    const fn of<S: Solution>() -> Self {
+        Self {
+            day: S::DAY,
+            parse: parse::<S>,
+            run: run::<S>,
+            time: time::<S>,
+            explain: explain::<S>,
+        }
+    }
+
+    /// Only parses `input`, reporting whether it is valid.
+    pub fn parse(&self, input: &str) -> Result<(), ParseError> {
+        (self.parse)(input)
+    }
+
+    /// Parses `input` once and returns the answer to each of `parts`, in order.
+    pub fn solve(&self, input: &str, parts: &[Part]) -> Result<Vec<Answer>, SolveError> {
+        let run = self.run(input, parts)?;
+        Ok(run.parts.into_iter().map(|part| part.answer).collect())
+    }
+
+    /// Like [`solve`](Solver::solve), but also reports how long parsing and
+    /// each part took.
+    pub fn run(&self, input: &str, parts: &[Part]) -> Result<Run, SolveError> {
+        (self.run)(input, parts)
+    }
+
+    /// Runs parsing and both parts once, timing each phase separately.
+    pub fn time(&self, input: &str) -> Result<Timing, SolveError> {
+        (self.time)(input)
+    }
+
+    /// The decisions behind the answer to `part`, or `None` if the day cannot
+    /// explain its answers.
+    pub fn explain(&self, input: &str, part: Part) -> Result<Explanation, ParseError> {
+        (self.explain)(input, part)
+    }
+}
+
+fn parse<S: Solution>(input: &str) -> Result<(), ParseError> {
+    S::parse(input).map(drop)
+}
+
+fn run<S: Solution>(input: &str, parts: &[Part]) -> Result<Run, SolveError> {
+    let start = Instant::now();
+    let input = S::parse(input)?;
+    let parse = start.elapsed();
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = match part {
+                Part::One => S::part1(&input).map(|a| Answer::new(part, a)),
+                Part::Two => S::part2(&input).map(|a| Answer::new(part, a)),
+            };
+            let answer = answer.map_err(|overflow| SolveError::Overflow { part, overflow })?;
+            Ok(PartRun {
+                answer,
+                elapsed: start.elapsed(),
+            })
+        })
+        .collect::<Result<_, SolveError>>()?;
+    Ok(Run { parse, parts })
+}
+
+fn explain<S: Solution>(input: &str, part: Part) -> Result<Explanation, ParseError> {
+    Ok(S::explain(&S::parse(input)?, part))
+}
+
+fn time<S: Solution>(input: &str) -> Result<Timing, SolveError> {
+    let overflowed = |part| move |overflow| SolveError::Overflow { part, overflow };
+
+    let start = Instant::now();
+    let input = S::parse(black_box(input))?;
+    let parse = start.elapsed();
+
+    let start = Instant::now();
+    black_box(S::part1(&input).map_err(overflowed(Part::One))?);
+    let part1 = start.elapsed();
+
+    let start = Instant::now();
+    black_box(S::part2(&input).map_err(overflowed(Part::Two))?);
+    let part2 = start.elapsed();
+
+    Ok(Timing {
+        parse,
+        part1,
+        part2,
+    })
+}
+
+/// Every registered day, in order.
+pub const SOLVERS: &[Solver] = &[
+    Solver::of::<day01::Day01>(),
+    Solver::of::<day02::Day02>(),
+    Solver::of::<day03::Day03>(),
+    Solver::of::<day04::Day04>(),
+    Solver::of::<day05::Day05>(),
+    Solver::of::<day06::Day06>(),
+    Solver::of::<day07::Day07>(),
+    Solver::of::<day08::Day08>(),
+    Solver::of::<day09::Day09>(),
+    Solver::of::<day10::Day10>(),
+    Solver::of::<day11::Day11>(),
+    Solver::of::<day12::Day12>(),
+];
+
+/// Looks up the solver registered for `day`.
+pub fn solver(day: u8) -> Option<Solver> {
+    SOLVERS.iter().find(|solver| solver.day == day).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solver_returns_answers_in_requested_order() {
+        let input = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+        let answers = solver(1).unwrap().solve(input, &[Part::Two, Part::One]);
+        let answers = answers.unwrap();
+        assert_eq!(
+            answers,
+            vec![Answer::new(Part::Two, 31), Answer::new(Part::One, 11)]
+        );
+    }
+
+    #[test]
+    fn run_times_each_requested_part() {
+        let input = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+        let run = solver(1).unwrap().run(input, &[Part::Two]).unwrap();
+        assert_eq!(run.parts.len(), 1);
+        assert_eq!(run.parts[0].answer, Answer::new(Part::Two, 31));
+    }
+
+    #[test]
+    fn parse_errors_are_returned() {
+        let e = solver(1)
+            .unwrap()
+            .solve("3   4\n4   x\n", &Part::ALL)
+            .unwrap_err();
+        let SolveError::Parse(e) = e else {
+            panic!("expected a parse error, got {e:?}");
+        };
+        assert_eq!((e.line, e.column), (2, 5));
+    }
+
+    #[test]
+    fn explanations_add_up_to_the_answers() {
+        for solver in SOLVERS {
+            let Some(input) =
+                crate::gen::generate(solver.day, crate::gen::Params { seed: 7, size: 40 })
+            else {
+                continue;
+            };
+            for part in Part::ALL {
+                let Some(decisions) = solver.explain(&input, part).unwrap() else {
+                    continue;
+                };
+                let total: u64 = decisions.iter().map(|d| d.contribution).sum();
+                let answer = &solver.solve(&input, &[part]).unwrap()[0];
+                assert_eq!(
+                    total.to_string(),
+                    answer.value,
+                    "day {} part {part}",
+                    solver.day
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn unsolved_days_are_not_registered() {
+        assert!(solver(25).is_none());
+    }
+}